@@ -11,11 +11,11 @@ fn bench_clones(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['a' as u8; 15]).unwrap()),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'a'; 15]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("clone");
     // for ns time ops one second is plenty
@@ -60,11 +60,11 @@ fn bench_try_from(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['a' as u8; 15]).unwrap()),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'a'; 15]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("try_from");
     // for ns time ops 0.1 second is plenty
@@ -109,10 +109,10 @@ fn bench_push(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("push_str");
     // for ns time ops 0.1 second is plenty
@@ -164,6 +164,65 @@ fn bench_push(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(string, bench_clones, bench_try_from, bench_push);
+fn bench_retain(c: &mut Criterion) {
+    let params = [
+        (""),
+        ("a"),
+        ("acb"),
+        ("abcdefg"),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
+    ];
+    let mut group = c.benchmark_group("retain");
+    // for ns time ops 0.1 second is plenty
+    group.measurement_time(Duration::from_millis(TIME));
+    group.warm_up_time(Duration::from_millis(TIME));
+    for param in params {
+        macro_rules! build {
+            ($($str:path: $f:ident),*$(,)*) => {
+                $(
+                    if let Ok(Ok(string)) = std::panic::catch_unwind(|| { <$str>::try_from(param) }) {
+                        if (&string as &str) == param {
+                            group.bench_with_input(
+                                BenchmarkId::new(stringify!($str::$f), param.len()),
+                                &string,
+                                |b, p| b.iter(|| {
+                                    let mut string = p.clone();
+                                    let mut keep = false;
+                                    let _ = string.$f(|_| {
+                                        keep = !keep;
+                                        keep
+                                    });
+                                    string
+                                }),
+                            );
+                        }
+                    }
+                )*
+            };
+        }
+        build!(
+            std::string::String : retain,
+            ArrayString<7> : retain,
+            ArrayString<63> : retain,
+            ArrayString<255> : retain,
+            ArrayString<7> : retain_mut,
+            ArrayString<63> : retain_mut,
+            ArrayString<255> : retain_mut,
+            CacheString : retain,
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    string,
+    bench_clones,
+    bench_try_from,
+    bench_push,
+    bench_retain
+);
 
 criterion_main!(string);
@@ -1,20 +1,48 @@
 //! `ArrayString` definition and Api implementation
 #![allow(clippy::len_without_is_empty)]
 
-use crate::arraystring::sealed::ValidCapacity;
-use crate::utils::{is_char_boundary, is_inside_boundary};
-use crate::utils::{truncate_str, IntoLossy};
+use crate::arraystring::sealed::{length_to_usize, usize_to_length, Length};
+use crate::line::{push_utf8, PartialChar, ReadLine};
+use crate::utils::{is_char_boundary, is_inside_boundary, is_valid_range};
+use crate::utils::{truncate_str, IntoLossy, OverflowCheck};
+use crate::{parts::Parts, pattern::Pattern};
 use crate::{prelude::*, Error};
 use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
-use core::{cmp::min, ops::*};
+use core::iter::{once, Chain, Once};
+use core::{cmp::min, fmt, mem::MaybeUninit, ops::*, str};
 #[cfg(feature = "logs")]
 use log::{debug, trace};
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
 use no_panic::no_panic;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
 
 /// String based on a generic array (size defined at compile time through `const generics`)
 ///
-/// Can't outgrow capacity (defined at compile time), always occupies [`capacity`] `+ 1` bytes of memory
+/// Can't outgrow capacity (defined at compile time), always occupies [`capacity`] `+ size_of::<L>()` bytes of memory
+///
+/// The length is stored as `L`, which defaults to `u8` (capacities up to 255 bytes). Bigger capacities need a wider length type, `u16` (up to 65535 bytes) or `usize`, using a capacity that doesn't fit in `L` fails to compile.
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type LogLine = ArrayString<1024, u16>;
+///
+/// let line = LogLine::try_from_str("0".repeat(1000))?;
+/// assert_eq!(line.len(), 1000);
+/// assert_eq!(core::mem::size_of::<LogLine>(), 1026);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use arraystring::prelude::*;
+/// // 256 bytes don't fit in the default `u8` length
+/// let string = ArrayString::<256>::new();
+/// ```
 ///
 /// *Doesn't allocate memory on the heap and never panics (all panic branches are stripped at compile time)*
 ///
@@ -25,17 +53,20 @@ use no_panic::no_panic;
     derive(diesel::AsExpression, diesel::FromSqlRow)
 )]
 #[cfg_attr(feature = "diesel-traits", diesel(sql_type = diesel::sql_types::Text))]
-pub struct ArrayString<const N: usize> {
+pub struct ArrayString<const N: usize, L: Length = u8> {
     /// Array type corresponding to specified `SIZE`
     pub(crate) array: [u8; N],
     /// Current string size
-    pub(crate) size: u8,
+    pub(crate) size: L,
 }
 
-impl<const N: usize> ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> ArrayString<N, L> {
+    /// Fails compilation if the capacity doesn't fit in the length type
+    const CAPACITY_FITS: () = assert!(
+        N <= L::MAX,
+        "ArrayString capacity is too big for its length type, use a wider one (u16 or usize)"
+    );
+
     /// Creates new empty string.
     ///
     /// ```rust
@@ -46,10 +77,51 @@ where
     /// ```
     #[inline]
     pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_FITS;
         Self {
             array: [0; N],
-            size: 0,
+            size: L::ZERO,
+        }
+    }
+
+    /// Creates new `ArrayString` from a string literal, in a const context it fails to compile if the literal is bigger than [`capacity`]
+    ///
+    /// Meant for `const` and `static` items, if called at runtime it panics instead, prefer [`try_from_str`] there.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`try_from_str`]: ./struct.ArrayString.html#method.try_from_str
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// static DEFAULT_ROLE: ArrayString<5> = ArrayString::from_static("admin");
+    /// const ROLES: [ArrayString<5>; 2] = [ArrayString::from_static("user"), ArrayString::from_static("guest")];
+    ///
+    /// assert_eq!(DEFAULT_ROLE.as_str(), "admin");
+    /// assert_eq!(ROLES[1].as_str(), "guest");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use arraystring::prelude::*;
+    /// // Literal doesn't fit in 5 bytes
+    /// static DEFAULT_ROLE: ArrayString<5> = ArrayString::from_static("administrator");
+    /// ```
+    #[inline]
+    pub const fn from_static(string: &'static str) -> Self {
+        let bytes = string.as_bytes();
+        assert!(
+            bytes.len() <= N,
+            "string literal is bigger than ArrayString capacity"
+        );
+        let mut out = Self::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            out.array[i] = bytes[i];
+            i += 1;
         }
+        out.size = usize_to_length(bytes.len());
+        out
     }
 
     /// Creates new `ArrayString` from string slice if length is lower or equal to [`capacity`], otherwise returns an error.
@@ -103,6 +175,36 @@ where
         s
     }
 
+    /// Creates new `ArrayString` from string slice assuming length is appropriate.
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `string.len()` > [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let filled = "0".repeat(ArrayString::<23>::capacity());
+    /// let string = unsafe {
+    ///     ArrayString::<23>::from_str_unchecked(&filled)
+    /// };
+    /// assert_eq!(string.as_str(), filled.as_str());
+    ///
+    /// // Undefined behavior, don't do it
+    /// // let out_of_bounds = "0".repeat(ArrayString::<23>::capacity() + 1);
+    /// // let ub = unsafe { ArrayString::<23>::from_str_unchecked(out_of_bounds) };
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn from_str_unchecked(string: impl AsRef<str>) -> Self {
+        trace!("FromStr unchecked: {}", string.as_ref());
+        let mut out = Self::new();
+        out.push_str_unchecked(string);
+        out
+    }
+
     /// Creates new `ArrayString` from string slice iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
@@ -131,6 +233,40 @@ where
         Ok(out)
     }
 
+    /// Creates new `ArrayString` from string slice iterator, if an item doesn't fit returns what was built with the leftover items
+    ///
+    /// Items are never split, the first leftover is the item that didn't fit, followed by the ones that weren't consumed.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let words = "wrap these words in lines".split_inclusive(' ');
+    /// let (line, leftover) = ArrayString::<11>::try_from_iterator_partial(words).unwrap_err();
+    /// assert_eq!(line.as_str(), "wrap these ");
+    ///
+    /// let (line, leftover) = ArrayString::<11>::try_from_iterator_partial(leftover).unwrap_err();
+    /// assert_eq!(line.as_str(), "words in ");
+    ///
+    /// let line = ArrayString::<11>::try_from_iterator_partial(leftover).unwrap();
+    /// assert_eq!(line.as_str(), "lines");
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_iterator_partial<S: AsRef<str>, I: IntoIterator<Item = S>>(
+        iter: I,
+    ) -> Result<Self, (Self, Chain<Once<S>, I::IntoIter>)> {
+        trace!("FromIterator partial");
+        let mut out = Self::new();
+        let mut iter = iter.into_iter();
+        while let Some(s) = iter.next() {
+            if out.try_push_str(s.as_ref()).is_err() {
+                return Err((out, once(s).chain(iter)));
+            }
+        }
+        Ok(out)
+    }
+
     /// Creates new `ArrayString` from string slice iterator truncating size if bigger than [`capacity`].
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
@@ -220,6 +356,37 @@ where
         out
     }
 
+    /// Creates new `ArrayString` from char iterator, if a char doesn't fit returns what was built with the leftover chars
+    ///
+    /// The first leftover is the char that didn't fit, followed by the ones that weren't consumed.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let (chunk, leftover) = ArrayString::<5>::try_from_chars_partial("ABCD🤔E".chars()).unwrap_err();
+    /// assert_eq!(chunk.as_str(), "ABCD");
+    /// assert_eq!(leftover.collect::<String>(), "🤔E");
+    ///
+    /// let chunk = ArrayString::<5>::try_from_chars_partial("🤔E".chars()).unwrap();
+    /// assert_eq!(chunk.as_str(), "🤔E");
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_chars_partial<I: IntoIterator<Item = char>>(
+        iter: I,
+    ) -> Result<Self, (Self, Chain<Once<char>, I::IntoIter>)> {
+        trace!("TryFrom chars partial");
+        let mut out = Self::new();
+        let mut iter = iter.into_iter();
+        while let Some(ch) = iter.next() {
+            if out.try_push(ch).is_err() {
+                return Err((out, once(ch).chain(iter)));
+            }
+        }
+        Ok(out)
+    }
+
     /// Creates new `ArrayString` from `u16` slice, returning [`Utf16`] on invalid utf-16 data or [`OutOfBounds`] if bigger than [`capacity`]
     ///
     /// [`Utf16`]: ./error/enum.Error.html#variant.Utf16
@@ -235,10 +402,10 @@ where
     /// assert_eq!(string.as_str(), "𝄞music");
     ///
     /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-    /// assert_eq!(ArrayString::<23>::try_from_utf16(invalid_utf16), Err(Error::Utf16));
+    /// assert!(matches!(ArrayString::<23>::try_from_utf16(invalid_utf16), Err(Error::Utf16(_))));
     ///
     /// let out_of_bounds: Vec<_> = (0..300).map(|_| 0).collect();
-    /// assert_eq!(ArrayString::<23>::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds));
+    /// assert!(matches!(ArrayString::<23>::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds(_))));
     /// # Ok(())
     /// # }
     /// ```
@@ -267,7 +434,7 @@ where
     /// assert_eq!(string.as_str(), "𝄞music");
     ///
     /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-    /// assert_eq!(ArrayString::<23>::from_utf16_truncate(invalid_utf16), Err(Utf16));
+    /// assert!(matches!(ArrayString::<23>::from_utf16_truncate(invalid_utf16), Err(Utf16 { .. })));
     ///
     /// let out_of_bounds: Vec<u16> = (0..300).map(|_| 0).collect();
     /// assert_eq!(ArrayString::<23>::from_utf16_truncate(out_of_bounds)?.as_str(),
@@ -322,243 +489,1489 @@ where
         out
     }
 
-    /// Extracts a string slice containing the entire `ArrayString`
+    /// Creates new `ArrayString` from byte slice, returning [`Utf8`] on invalid utf-8 data or [`OutOfBounds`] if bigger than [`capacity`]
+    ///
+    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let s = ArrayString::<23>::try_from_str("My String")?;
-    /// assert_eq!(s.as_str(), "My String");
+    /// let string = ArrayString::<23>::try_from_utf8("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// let invalid_utf8 = [0, 159, 146, 150];
+    /// assert!(matches!(ArrayString::<23>::try_from_utf8(invalid_utf8), Err(Error::Utf8(_))));
+    ///
+    /// let out_of_bounds = "0".repeat(300);
+    /// assert!(matches!(ArrayString::<23>::try_from_utf8(out_of_bounds.as_bytes()), Err(Error::OutOfBounds(_))));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn as_str(&self) -> &str {
-        trace!("As str");
-        self.as_ref()
+    pub fn try_from_utf8(slice: impl AsRef<[u8]>) -> Result<Self, Error> {
+        debug!("From utf8: {:?}", slice.as_ref());
+        Ok(Self::try_from_str(str::from_utf8(slice.as_ref())?)?)
     }
 
-    /// Extracts a mutable string slice containing the entire `ArrayString`
+    /// Creates new `ArrayString` from byte slice, returning [`Utf8`] on invalid utf-8 data, truncating if bigger than [`capacity`].
+    ///
+    /// Only the bytes that fit are validated, truncation happens at the last char boundary.
+    ///
+    /// [`Utf8`]: ./error/struct.Utf8.html
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
-    /// assert_eq!(s.as_mut_str(), "My String");
+    /// let string = ArrayString::<23>::from_utf8_truncate("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// let invalid_utf8 = [0, 159, 146, 150];
+    /// assert!(matches!(ArrayString::<23>::from_utf8_truncate(invalid_utf8), Err(Utf8 { .. })));
+    ///
+    /// assert_eq!(ArrayString::<6>::from_utf8_truncate("ab🤔🤔")?.as_str(), "ab🤔");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn as_mut_str(&mut self) -> &mut str {
-        trace!("As mut str: {self}");
-        self.as_mut()
+    pub fn from_utf8_truncate(slice: impl AsRef<[u8]>) -> Result<Self, Utf8> {
+        debug!("From utf8 truncate: {:?}", slice.as_ref());
+        let slice = slice.as_ref();
+        let fits = slice.get(..Self::capacity()).unwrap_or(slice);
+        let string = match str::from_utf8(fits) {
+            Ok(string) => string,
+            // A char was cut by the capacity, only the bytes before it are kept
+            Err(err) if err.error_len().is_none() && fits.len() < slice.len() => {
+                // Safety: `valid_up_to` is a char boundary inside `fits` and the bytes before it are valid utf-8
+                unsafe { str::from_utf8_unchecked(fits.get_unchecked(..err.valid_up_to())) }
+            }
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self::from_str_truncate(string))
     }
 
-    /// Returns maximum string capacity, defined at compile time, it will never change
+    /// Creates new `ArrayString` from byte slice, replacing invalid utf-8 data with `REPLACEMENT_CHARACTER` (\u{FFFD}) and truncating size if bigger than [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = ArrayString::<23>::from_utf8_lossy_truncate("My String");
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// let invalid_utf8 = b"Hello \xF0\x90\x80World";
+    /// assert_eq!(ArrayString::<23>::from_utf8_lossy_truncate(invalid_utf8).as_str(), "Hello \u{FFFD}World");
+    ///
+    /// let out_of_bounds = "0".repeat(300);
+    /// assert_eq!(ArrayString::<23>::from_utf8_lossy_truncate(out_of_bounds).as_str(),
+    ///            "0".repeat(ArrayString::<23>::capacity()).as_str());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn from_utf8_lossy_truncate(slice: impl AsRef<[u8]>) -> Self {
+        debug!("From utf8 lossy: {:?}", slice.as_ref());
+        let mut out = Self::new();
+        for chunk in slice.as_ref().utf8_chunks() {
+            if out.try_push_str(chunk.valid()).is_err() {
+                out.push_str_truncate(chunk.valid());
+                break;
+            }
+            if !chunk.invalid().is_empty() && out.try_push(REPLACEMENT_CHARACTER).is_err() {
+                break;
+            }
+        }
+        out
+    }
+
+    /// Creates new `ArrayString` from byte slice assuming it's utf-8 and of a appropriate size.
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `slice` is not a valid utf-8 string or `slice.len()` > [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
     /// # use arraystring::prelude::*;
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// assert_eq!(ArrayString::<32>::capacity(), 32);
+    /// let string = unsafe { ArrayString::<23>::from_utf8_unchecked("My String") };
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// // Undefined behavior, don't do it
+    /// // let out_of_bounds = "0".repeat(300);
+    /// // let ub = unsafe { ArrayString::<23>::from_utf8_unchecked(out_of_bounds) };
     /// ```
     #[inline]
-    pub const fn capacity() -> usize {
-        N
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn from_utf8_unchecked(slice: impl AsRef<[u8]>) -> Self {
+        trace!("From utf8 unchecked: {:?}", slice.as_ref());
+        debug_assert!(str::from_utf8(slice.as_ref()).is_ok());
+        Self::from_str_unchecked(str::from_utf8_unchecked(slice.as_ref()))
     }
 
-    /// Pushes string slice to the end of the `ArrayString` if total size is lower or equal to [`capacity`], otherwise returns an error.
+    /// Creates new `ArrayString` from formatting arguments if the formatted length is lower or equal to [`capacity`], otherwise returns an error.
+    ///
+    /// Usually called through [`try_format!`]. If a formatting trait implementation returns an error the output written until then is kept, like `format!` it's considered a bug in that implementation.
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`try_format!`]: ./macro.try_format.html
     ///
     /// ```rust
-    /// # use arraystring::{Error, prelude::*};
-    /// # fn main() -> Result<(), Error> {
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<255>::try_from_str("My String")?;
-    /// s.try_push_str(" My other String")?;
-    /// assert_eq!(s.as_str(), "My String My other String");
+    /// let string = ArrayString::<23>::try_from_fmt(format_args!("{} + {} = {}", 1, 2, 1 + 2))?;
+    /// assert_eq!(string.as_str(), "1 + 2 = 3");
     ///
-    /// assert!(s.try_push_str("0".repeat(ArrayString::<255>::capacity())).is_err());
+    /// let out_of_bounds = ArrayString::<5>::try_from_fmt(format_args!("{:>10}", 1));
+    /// assert!(matches!(out_of_bounds, Err(OutOfBounds { .. })));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), OutOfBounds> {
-        trace!("Push str: {}", string.as_ref());
-        let len = self.len();
-        self.replace_range(len..len, string)
-            .map_err(|_| OutOfBounds)
+    pub fn try_from_fmt(args: fmt::Arguments) -> Result<Self, OutOfBounds> {
+        trace!("Try from fmt: {args}");
+        let mut out = Self::new();
+        let mut writer = OverflowCheck::new(&mut out);
+        let _ = fmt::write(&mut writer, args);
+        if writer.overflowed {
+            return Err(OutOfBounds::new(writer.required, N));
+        }
+        Ok(out)
     }
 
-    /// Pushes string slice to the end of the `ArrayString` truncating total size if bigger than [`capacity`].
+    /// Creates new `ArrayString` from formatting arguments truncating size if bigger than [`capacity`].
+    ///
+    /// Truncation happens at the last char boundary that fits, everything formatted after it is dropped. Usually called through [`format_truncate!`].
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`format_truncate!`]: ./macro.format_truncate.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = ArrayString::<23>::from_fmt_truncate(format_args!("{} + {} = {}", 1, 2, 1 + 2));
+    /// assert_eq!(string.as_str(), "1 + 2 = 3");
+    ///
+    /// let truncated = ArrayString::<6>::from_fmt_truncate(format_args!("{}{}", "ab", "🤔🤔"));
+    /// assert_eq!(truncated.as_str(), "ab🤔");
+    /// ```
+    #[inline]
+    pub fn from_fmt_truncate(args: fmt::Arguments) -> Self {
+        trace!("From fmt truncate: {args}");
+        let mut out = Self::new();
+        let _ = out.write_fmt_truncate(args);
+        out
+    }
+
+    /// Moves the content to an `ArrayString` with a capacity at least as big, it fails to compile if `M` is smaller than `N`
+    ///
+    /// The length type may change too, like moving a [`MaxString`] to an `ArrayString<4096, u16>`. For fallible conversions to any capacity use `TryFrom<&ArrayString<N, L>>`
+    ///
+    /// [`MaxString`]: ./type.MaxString.html
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<255>::try_from_str("My String")?;
-    /// s.push_str_truncate(" My other String");
-    /// assert_eq!(s.as_str(), "My String My other String");
+    /// let s = ArrayString::<20>::try_from_str("My String🤔")?;
+    /// let wide: MaxString = s.widen();
+    /// assert_eq!(wide.as_str(), "My String🤔");
     ///
-    /// let mut s = ArrayString::<23>::default();
-    /// s.push_str_truncate("0".repeat(ArrayString::<23>::capacity() + 1));
-    /// assert_eq!(s.as_str(), "0".repeat(ArrayString::<23>::capacity()).as_str());
+    /// let path: ArrayString<4096, u16> = wide.widen();
+    /// assert_eq!(path.as_str(), "My String🤔");
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// ```compile_fail
+    /// # use arraystring::prelude::*;
+    /// // 20 bytes may not fit in 10
+    /// let narrow: ArrayString<10> = ArrayString::<20>::new().widen();
+    /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn push_str_truncate(&mut self, string: impl AsRef<str>) {
-        trace!("Push str truncate: {}", string.as_ref());
-        let _ = self.try_push_str(truncate_str(string.as_ref(), Self::capacity() - self.len()));
+    pub fn widen<const M: usize, L2: Length>(self) -> ArrayString<M, L2> {
+        const {
+            assert!(
+                M >= N,
+                "ArrayString can only widen to a capacity at least as big"
+            )
+        };
+        trace!("Widen {self:?} from {N} to {M}");
+        // Safety: `M >= N >= self.len()` is checked at compile time
+        unsafe { ArrayString::from_str_unchecked(self.as_str()) }
     }
 
-    /// Inserts character to the end of the `ArrayString` erroring if total size if bigger than [`capacity`].
-    ///
-    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// Moves the content to an `ArrayString` of any capacity, truncating it at the last char boundary that fits
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
-    /// s.try_push('!')?;
-    /// assert_eq!(s.as_str(), "My String!");
+    /// let s = MaxString::try_from_str("ABCD🤔")?;
+    /// let narrow: ArrayString<6> = s.narrow_truncate();
+    /// assert_eq!(narrow.as_str(), "ABCD");
     ///
-    /// let mut s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
-    /// assert!(s.try_push('!').is_err());
+    /// let narrow: ArrayString<8> = s.narrow_truncate();
+    /// assert_eq!(narrow.as_str(), "ABCD🤔");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_push(&mut self, ch: char) -> Result<(), OutOfBounds> {
-        trace!("Push: {}", ch);
-        let mut buf = [0; 4];
-        self.try_push_str(ch.encode_utf8(&mut buf))
+    pub fn narrow_truncate<const M: usize, L2: Length>(self) -> ArrayString<M, L2> {
+        trace!("Narrow truncate {self:?} from {N} to {M}");
+        ArrayString::from_str_truncate(self.as_str())
     }
 
-    /// Truncates `ArrayString` to specified size (if smaller than current size and a valid utf-8 char index).
+    /// Extracts a string slice containing the entire `ArrayString`
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
-    /// s.truncate(5)?;
-    /// assert_eq!(s.as_str(), "My St");
-    ///
-    /// // Does nothing
-    /// s.truncate(6)?;
-    /// assert_eq!(s.as_str(), "My St");
-    ///
-    /// // Index is not at a valid char
-    /// let mut s = ArrayString::<23>::try_from_str("🤔")?;
-    /// assert!(s.truncate(1).is_err());
+    /// let s = ArrayString::<23>::try_from_str("My String")?;
+    /// assert_eq!(s.as_str(), "My String");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn truncate(&mut self, size: usize) -> Result<(), Utf8> {
-        debug!("Truncate: {}", size);
-        let len = min(self.len(), size);
-        is_char_boundary(self, len).map(|()| self.size = len.into_lossy())
+    pub const fn as_str(&self) -> &str {
+        // Safety: len will always be between 0 and capacity, so the slice is always inside the array
+        debug_assert!(self.len() <= N);
+        let bytes = unsafe { core::slice::from_raw_parts(self.array.as_ptr(), self.len()) };
+        // Safety: our byte slice should only contain valid utf-8
+        // There is no way to invalidate the utf-8 of it from safe functions
+        // And it's a invariant expected to be kept in unsafe functions
+        debug_assert!(str::from_utf8(bytes).is_ok());
+        unsafe { str::from_utf8_unchecked(bytes) }
     }
 
-    /// Removes last character from `ArrayString`, if any.
+    /// Extracts a mutable string slice containing the entire `ArrayString`
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("A🤔")?;
-    /// assert_eq!(s.pop(), Some('🤔'));
-    /// assert_eq!(s.pop(), Some('A'));
-    /// assert_eq!(s.pop(), None);
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// assert_eq!(s.as_mut_str(), "My String");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn pop(&mut self) -> Option<char> {
-        debug!("Pop");
-        self.as_str().chars().last().map(|ch| {
-            self.size -= ch.len_utf8().into_lossy();
-            ch
-        })
+    pub fn as_mut_str(&mut self) -> &mut str {
+        trace!("As mut str: {self}");
+        self.as_mut()
     }
 
-    /// Removes whitespaces from the beggining and end of the string
+    /// Extracts a mutable byte slice containing the entire `ArrayString`
+    ///
+    /// # Safety
+    ///
+    /// It's UB to store invalid utf-8 data in the returned byte array
     ///
     /// ```rust
-    /// # use arraystring::prelude::*;
-    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut string = ArrayString::<255>::try_from_str("   to be trimmed     ")?;
-    /// string.trim();
-    /// assert_eq!(string.as_str(), "to be trimmed");
-    ///
-    /// let mut string = ArrayString::<23>::try_from_str("   🤔")?;
-    /// string.trim();
-    /// assert_eq!(string.as_str(), "🤔");
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// unsafe { s.as_mut_bytes()[0] = b'm' };
+    /// assert_eq!(s.as_str(), "my String");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn trim(&mut self) {
-        trace!("Trim: {self:?}");
-        let mut start = self.len();
-        for (pos, char) in self.as_str().char_indices() {
-            if !char.is_whitespace() {
-                start = pos;
-                break;
-            }
-        }
-        let mut end = self.len();
-        for (pos, char) in self.as_str().char_indices().rev() {
-            if pos < start {
-                self.size = 0;
-                return;
-            }
-            if !char.is_whitespace() {
-                break;
-            }
-            end = pos;
-        }
-
-        let _ = self.replace_range(end.., "");
-        let _ = self.replace_range(..start, "");
+    pub unsafe fn as_mut_bytes(&mut self) -> &mut [u8] {
+        trace!("As mut bytes: {self}");
+        let len = self.len();
+        // Safety: len will always be between 0 and capacity, so get_unchecked_mut will never fail
+        debug_assert!(len <= N);
+        self.array.get_unchecked_mut(..len)
     }
 
-    /// Removes specified char from `ArrayString`
+    /// Returns the unused part of the backing array, to be written to before calling [`commit_utf8`] or [`set_len`]
+    ///
+    /// [`commit_utf8`]: ./struct.ArrayString.html#method.commit_utf8
+    /// [`set_len`]: ./struct.ArrayString.html#method.set_len
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
-    /// assert_eq!(s.remove("ABCD🤔".len()), Err(Error::OutOfBounds));
-    /// assert_eq!(s.remove(10), Err(Error::OutOfBounds));
-    /// assert_eq!(s.remove(6), Err(Error::Utf8));
-    /// assert_eq!(s.remove(0), Ok('A'));
-    /// assert_eq!(s.as_str(), "BCD🤔");
-    /// assert_eq!(s.remove(2), Ok('D'));
-    /// assert_eq!(s.as_str(), "BC🤔");
+    /// let mut s = ArrayString::<23>::try_from_str("My")?;
+    /// let spare = s.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 21);
+    /// for (dst, src) in spare.iter_mut().zip(b" String") {
+    ///     dst.write(*src);
+    /// }
+    /// s.commit_utf8(7)?;
+    /// assert_eq!(s.as_str(), "My String");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        trace!("Spare capacity mut: {self}");
+        let len = self.len();
+        // Safety: len will always be between 0 and capacity, so the slice is always inside the array
+        // and `MaybeUninit<u8>` has the same layout as `u8`, writing any byte to it is safe
+        debug_assert!(len <= N);
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.array.as_mut_ptr().add(len).cast::<MaybeUninit<u8>>(),
+                N - len,
+            )
+        }
+    }
+
+    /// Forces the length of the `ArrayString` to `new_len`
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `new_len` > [`capacity`] or if the bytes up to `new_len` are not valid utf-8
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// unsafe { s.set_len(2) };
+    /// assert_eq!(s.as_str(), "My");
+    ///
+    /// // Undefined behavior, don't do it
+    /// // unsafe { s.set_len(24) };
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        trace!("Set len {new_len}: {self}");
+        debug_assert!(new_len <= Self::capacity());
+        self.size = new_len.into_lossy();
+        debug_assert!(str::from_utf8(self.as_ref()).is_ok());
+    }
+
+    /// Validates the next `n` bytes written through [`spare_capacity_mut`] and appends them to the `ArrayString`
+    ///
+    /// Returns [`OutOfBounds`] if `n` is bigger than the spare capacity and [`Utf8`] if the bytes are not valid utf-8, leaving the string unchanged
+    ///
+    /// [`spare_capacity_mut`]: ./struct.ArrayString.html#method.spare_capacity_mut
+    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("My")?;
+    /// s.spare_capacity_mut()[0].write(b'!');
+    /// s.commit_utf8(1)?;
+    /// assert_eq!(s.as_str(), "My!");
+    ///
+    /// s.spare_capacity_mut()[0].write(0xFF);
+    /// assert!(matches!(s.commit_utf8(1), Err(Error::Utf8(_))));
+    /// assert!(matches!(s.commit_utf8(21), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "My!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn commit_utf8(&mut self, n: usize) -> Result<(), Error> {
+        trace!("Commit utf8 {n}: {self}");
+        let len = self.len();
+        let out_of_bounds = OutOfBounds::new(len.saturating_add(n), N);
+        let new_len = len.checked_add(n).ok_or(out_of_bounds)?;
+        let new = self.array.get(len..new_len).ok_or(out_of_bounds)?;
+        let _ = str::from_utf8(new)?;
+        self.size = new_len.into_lossy();
+        Ok(())
+    }
+
+    /// Returns maximum string capacity, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<32>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// Pushes string slice to the end of the `ArrayString` if total size is lower or equal to [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<255>::try_from_str("My String")?;
+    /// s.try_push_str(" My other String")?;
+    /// assert_eq!(s.as_str(), "My String My other String");
+    ///
+    /// assert!(s.try_push_str("0".repeat(ArrayString::<255>::capacity())).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), OutOfBounds> {
+        trace!("Push str: {}", string.as_ref());
+        let len = self.len();
+        let required = len.saturating_add(string.as_ref().len());
+        self.replace_range(len..len, string)
+            .map_err(|_| OutOfBounds::new(required, N))
+    }
+
+    /// Pushes the biggest prefix of the string slice that fits, cut at a char boundary, returning the tail that didn't fit
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<7>::try_from_str("AB")?;
+    /// assert_eq!(s.try_push_str_partial("CD🤔EF"), Err("🤔EF"));
+    /// assert_eq!(s.as_str(), "ABCD");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.try_push_str_partial("🤔EF"), Ok(()));
+    /// assert_eq!(s.as_str(), "🤔EF");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str_partial<'a>(&mut self, string: &'a str) -> Result<(), &'a str> {
+        trace!("Push str partial: {string}");
+        let fits = truncate_str(string, Self::capacity().saturating_sub(self.len()));
+        // Safety: `truncate_str` keeps it within capacity
+        unsafe { self.push_str_unchecked(fits) };
+        match string.get(fits.len()..) {
+            Some(rest) if !rest.is_empty() => Err(rest),
+            _ => Ok(()),
+        }
+    }
+
+    /// Pushes string slice to the end of the `ArrayString` truncating total size if bigger than [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<255>::try_from_str("My String")?;
+    /// s.push_str_truncate(" My other String");
+    /// assert_eq!(s.as_str(), "My String My other String");
+    ///
+    /// let mut s = ArrayString::<23>::default();
+    /// s.push_str_truncate("0".repeat(ArrayString::<23>::capacity() + 1));
+    /// assert_eq!(s.as_str(), "0".repeat(ArrayString::<23>::capacity()).as_str());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn push_str_truncate(&mut self, string: impl AsRef<str>) {
+        trace!("Push str truncate: {}", string.as_ref());
+        let _ = self.try_push_str(truncate_str(string.as_ref(), Self::capacity() - self.len()));
+    }
+
+    /// Pushes string slice to the end of the `ArrayString` assuming total size is appropriate.
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `self.len() + string.len()` > [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<255>::try_from_str("My String")?;
+    /// unsafe { s.push_str_unchecked(" My other String") };
+    /// assert_eq!(s.as_str(), "My String My other String");
+    ///
+    /// // Undefined behavior, don't do it
+    /// // let mut undefined_behavior = ArrayString::<23>::default();
+    /// // undefined_behavior.push_str_unchecked("0".repeat(ArrayString::<23>::capacity() + 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn push_str_unchecked(&mut self, string: impl AsRef<str>) {
+        let str = string.as_ref().as_bytes();
+        trace!("Push str unchecked: {}", string.as_ref());
+        debug_assert!(self.len() + str.len() <= Self::capacity());
+        // Safety: the caller guarantees that `str` fits in the available space (`Self::capacity() - self.len()`)
+        core::ptr::copy_nonoverlapping(
+            str.as_ptr(),
+            self.array.as_mut_ptr().add(self.len()),
+            str.len(),
+        );
+        self.size = (self.len() + str.len()).into_lossy();
+    }
+
+    /// Inserts character to the end of the `ArrayString` erroring if total size if bigger than [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// s.try_push('!')?;
+    /// assert_eq!(s.as_str(), "My String!");
+    ///
+    /// let mut s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
+    /// assert!(s.try_push('!').is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), OutOfBounds> {
+        trace!("Push: {}", ch);
+        let mut buf = [0; 4];
+        self.try_push_str(ch.encode_utf8(&mut buf))
+    }
+
+    /// Inserts character to the end of the `ArrayString` assuming length is appropriate
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `self.len() + ch.len_utf8()` > [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// unsafe { s.push_unchecked('!') };
+    /// assert_eq!(s.as_str(), "My String!");
+    ///
+    /// // s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
+    /// // Undefined behavior, don't do it
+    /// // s.push_unchecked('!');
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn push_unchecked(&mut self, ch: char) {
+        trace!("Push unchecked: {}", ch);
+        let mut buf = [0; 4];
+        self.push_str_unchecked(ch.encode_utf8(&mut buf));
+    }
+
+    /// Returns a `fmt::Write` adapter that appends to the `ArrayString`, truncating at the last char boundary that fits instead of failing.
+    ///
+    /// See [`Truncating`].
+    ///
+    /// [`Truncating`]: ./struct.Truncating.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use core::fmt::Write;
+    /// # fn main() -> Result<(), core::fmt::Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<6>::try_from_str("ab").unwrap();
+    /// let mut writer = s.truncating();
+    /// write!(writer, "{}", "🤔🤔")?;
+    /// assert_eq!(writer.dropped(), 4);
+    /// assert_eq!(s.as_str(), "ab🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn truncating(&mut self) -> Truncating<'_, N, L> {
+        Truncating::new(self)
+    }
+
+    /// Appends formatting arguments to the `ArrayString`, truncating at the last char boundary that fits in [`capacity`].
+    ///
+    /// Returns how many bytes were dropped, everything formatted after the first overflow is dropped, so the string always ends with a prefix of the formatted text.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<10>::new();
+    /// assert_eq!(s.write_fmt_truncate(format_args!("{}:{}", "host", 80)), 0);
+    /// assert_eq!(s.as_str(), "host:80");
+    ///
+    /// // Marks the truncation with an ellipsis
+    /// s.clear();
+    /// if s.write_fmt_truncate(format_args!("{}:{}", "localhost", 8080)) > 0 {
+    ///     s.truncate(ArrayString::<10>::capacity() - '…'.len_utf8()).unwrap();
+    ///     s.try_push('…').unwrap();
+    /// }
+    /// assert_eq!(s.as_str(), "localho…");
+    /// ```
+    #[inline]
+    pub fn write_fmt_truncate(&mut self, args: fmt::Arguments) -> usize {
+        trace!("Write fmt truncate: {args}");
+        let mut writer = self.truncating();
+        let _ = fmt::write(&mut writer, args);
+        writer.dropped()
+    }
+
+    /// Returns a reader over the bytes of the `ArrayString`, implementing `io::Read` and `io::BufRead`
+    ///
+    /// See [`Reader`].
+    ///
+    /// [`Reader`]: ./struct.Reader.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use std::io::{self, Read};
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("My String").unwrap();
+    /// let mut read = Vec::new();
+    /// let _ = s.reader().read_to_end(&mut read)?;
+    /// assert_eq!(read, b"My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn reader(&self) -> Reader<'_> {
+        trace!("Reader: {self}");
+        Reader::new(self.as_bytes())
+    }
+
+    /// Returns a writer that appends to the `ArrayString`, implementing `io::Write`
+    ///
+    /// Unlike writing to the string directly, a char split between writes is kept until it's completed, see [`Writer`].
+    ///
+    /// [`Writer`]: ./struct.Writer.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use std::io::{self, Read};
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::new();
+    /// let mut input = "Olá, mundo!".as_bytes();
+    /// let mut writer = s.writer();
+    /// // Splits 'á' in half
+    /// io::copy(&mut (&mut input).take(3), &mut writer)?;
+    /// io::copy(&mut input, &mut writer)?;
+    /// assert_eq!(s.as_str(), "Olá, mundo!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn writer(&mut self) -> Writer<'_, N, L> {
+        trace!("Writer: {self}");
+        Writer::new(self)
+    }
+
+    /// Appends a line from `reader` (up to and including the newline) until the `ArrayString` is full, leaving the rest of the line in `reader`
+    ///
+    /// Returns `ErrorKind::InvalidData` if the line is not valid utf-8, keeping the string unchanged. Never allocates, no matter how long the line is.
+    ///
+    /// ```rust
+    /// # use arraystring::{prelude::*, ReadLine};
+    /// # use std::io;
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut input = io::Cursor::new("EHLO localhost\r\nQUIT\r\n");
+    /// let mut s = ArrayString::<8>::new();
+    /// assert_eq!(s.read_line_from(&mut input)?, ReadLine::Overflowed(8));
+    /// assert_eq!(s.as_str(), "EHLO loc");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.read_line_from(&mut input)?, ReadLine::Complete(8));
+    /// assert_eq!(s.as_str(), "alhost\r\n");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.read_line_from(&mut input)?, ReadLine::Complete(6));
+    /// assert_eq!(s.read_line_from(&mut input)?, ReadLine::Complete(0));
+    /// assert_eq!(s.as_str(), "QUIT\r\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_line_from(&mut self, reader: &mut impl io::BufRead) -> io::Result<ReadLine> {
+        trace!("Read line from reader to {self}");
+        self.read_line_with(reader, false)
+    }
+
+    /// Appends a line from `reader` (up to and including the newline), consuming and discarding what doesn't fit in the `ArrayString`
+    ///
+    /// Returns `ErrorKind::InvalidData` if the kept part of the line is not valid utf-8, keeping the string unchanged. Never allocates, no matter how long the line is.
+    ///
+    /// ```rust
+    /// # use arraystring::{prelude::*, ReadLine};
+    /// # use std::io;
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut input = io::Cursor::new("EHLO localhost\r\nQUIT\r\n");
+    /// let mut s = ArrayString::<8>::new();
+    /// assert_eq!(s.read_line_from_truncate(&mut input)?, ReadLine::Truncated { kept: 8, dropped: 8 });
+    /// assert_eq!(s.as_str(), "EHLO loc");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.read_line_from_truncate(&mut input)?, ReadLine::Complete(6));
+    /// assert_eq!(s.as_str(), "QUIT\r\n");
+    ///
+    /// let mut invalid = io::Cursor::new(b"\xFF\n");
+    /// let err = s.read_line_from_truncate(&mut invalid).unwrap_err();
+    /// assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    /// assert_eq!(s.as_str(), "QUIT\r\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn read_line_from_truncate(
+        &mut self,
+        reader: &mut impl io::BufRead,
+    ) -> io::Result<ReadLine> {
+        trace!("Read line from reader truncate to {self}");
+        self.read_line_with(reader, true)
+    }
+
+    /// Reads a line into the `ArrayString`, if it gets full the rest of the line is either left in `reader` or discarded
+    #[cfg(feature = "std")]
+    #[inline]
+    fn read_line_with(
+        &mut self,
+        reader: &mut impl io::BufRead,
+        truncate: bool,
+    ) -> io::Result<ReadLine> {
+        let start = self.len();
+        let mut partial = PartialChar::default();
+        let mut kept = 0;
+        loop {
+            let available = match reader.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if available.is_empty() && !partial.is_empty() {
+                let err = Utf8::new(self.len() - start, None);
+                self.size = start.into_lossy();
+                return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+            }
+            if available.is_empty() {
+                return Ok(ReadLine::Complete(kept));
+            }
+            let newline = available.iter().position(|byte| *byte == b'\n');
+            let line = available
+                .get(..newline.map_or(available.len(), |idx| idx + 1))
+                .unwrap_or(available);
+            let line_len = line.len();
+            let consumed = match push_utf8(self, &mut partial, line) {
+                Ok(consumed) => consumed,
+                Err(err) => {
+                    let err = err.with_valid_up_to(self.len() - start);
+                    reader.consume(line_len);
+                    self.size = start.into_lossy();
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+                }
+            };
+            reader.consume(consumed);
+            kept += consumed;
+            if consumed < line_len && truncate {
+                let dropped = skip_line(reader)?;
+                return Ok(ReadLine::Truncated { kept, dropped });
+            }
+            if consumed < line_len {
+                return Ok(ReadLine::Overflowed(kept));
+            }
+            if newline.is_some() {
+                return Ok(ReadLine::Complete(kept));
+            }
+        }
+    }
+
+    /// Appends a line from a byte iterator (up to and including the newline), consuming and discarding what doesn't fit in the `ArrayString`
+    ///
+    /// Doesn't need `std`. Returns [`Utf8`] if the kept part of the line is not valid utf-8, keeping the string unchanged. Never allocates, no matter how long the line is.
+    ///
+    /// [`Utf8`]: ./error/struct.Utf8.html
+    ///
+    /// ```rust
+    /// # use arraystring::{prelude::*, Error, ReadLine};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut input = "*2\r\n$5\r\nhello world\r\n".bytes();
+    /// let mut s = ArrayString::<8>::new();
+    /// assert_eq!(s.read_line_from_bytes_truncate(&mut input)?, ReadLine::Complete(4));
+    /// assert_eq!(s.as_str(), "*2\r\n");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.read_line_from_bytes_truncate(&mut input)?, ReadLine::Complete(4));
+    ///
+    /// s.clear();
+    /// assert_eq!(s.read_line_from_bytes_truncate(&mut input)?, ReadLine::Truncated { kept: 8, dropped: 5 });
+    /// assert_eq!(s.as_str(), "hello wo");
+    /// assert_eq!(input.next(), None);
+    ///
+    /// s.truncate(5)?;
+    /// let mut invalid = [b'!', 0xFF, b'\n'].into_iter();
+    /// assert!(matches!(s.read_line_from_bytes_truncate(&mut invalid), Err(Utf8 { .. })));
+    /// assert_eq!(s.as_str(), "hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn read_line_from_bytes_truncate(
+        &mut self,
+        bytes: &mut impl Iterator<Item = u8>,
+    ) -> Result<ReadLine, Utf8> {
+        trace!("Read line from bytes truncate to {self}");
+        let start = self.len();
+        let mut partial = PartialChar::default();
+        let mut kept = 0;
+        while let Some(byte) = bytes.next() {
+            let consumed = match push_utf8(self, &mut partial, &[byte]) {
+                Ok(consumed) => consumed,
+                Err(err) => {
+                    let err = err.with_valid_up_to(self.len() - start);
+                    self.size = start.into_lossy();
+                    return Err(err);
+                }
+            };
+            if consumed == 0 {
+                let mut dropped = 1;
+                if byte != b'\n' {
+                    for byte in bytes.by_ref() {
+                        dropped += 1;
+                        if byte == b'\n' {
+                            break;
+                        }
+                    }
+                }
+                return Ok(ReadLine::Truncated { kept, dropped });
+            }
+            kept += 1;
+            if byte == b'\n' {
+                return Ok(ReadLine::Complete(kept));
+            }
+        }
+        if !partial.is_empty() {
+            let err = Utf8::new(self.len() - start, None);
+            self.size = start.into_lossy();
+            return Err(err);
+        }
+        Ok(ReadLine::Complete(kept))
+    }
+
+    /// Truncates `ArrayString` to specified size (if smaller than current size and a valid utf-8 char index).
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("My String")?;
+    /// s.truncate(5)?;
+    /// assert_eq!(s.as_str(), "My St");
+    ///
+    /// // Does nothing
+    /// s.truncate(6)?;
+    /// assert_eq!(s.as_str(), "My St");
+    ///
+    /// // Index is not at a valid char
+    /// let mut s = ArrayString::<23>::try_from_str("🤔")?;
+    /// assert!(s.truncate(1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate(&mut self, size: usize) -> Result<(), NotCharBoundary> {
+        debug!("Truncate: {}", size);
+        let len = min(self.len(), size);
+        is_char_boundary(self, len).map(|()| self.size = len.into_lossy())
+    }
+
+    /// Truncates `ArrayString` to its first `n` chars, does nothing if it has fewer
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("Olá🤔 mundo")?;
+    /// s.truncate_chars(4);
+    /// assert_eq!(s.as_str(), "Olá🤔");
+    ///
+    /// s.truncate_chars(10);
+    /// assert_eq!(s.as_str(), "Olá🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate_chars(&mut self, n: usize) {
+        debug!("Truncate chars: {}", n);
+        if let Some((idx, _)) = self.as_str().char_indices().nth(n) {
+            self.size = idx.into_lossy();
+        }
+    }
+
+    /// Truncates `ArrayString` to its first `n` extended grapheme clusters, does nothing if it has fewer
+    ///
+    /// Unlike [`truncate_chars`] it never splits emoji sequences or combining accents
+    ///
+    /// [`truncate_chars`]: ./struct.ArrayString.html#method.truncate_chars
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<64>::try_from_str("e\u{301}👩‍👩‍👧 family")?;
+    /// s.truncate_graphemes(2);
+    /// assert_eq!(s.as_str(), "e\u{301}👩‍👩‍👧");
+    ///
+    /// let mut s = ArrayString::<64>::try_from_str("e\u{301}👩‍👩‍👧 family")?;
+    /// s.truncate_chars(2);
+    /// assert_eq!(s.as_str(), "e\u{301}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "unicode-segmentation")))]
+    #[cfg(feature = "unicode-segmentation")]
+    #[inline]
+    pub fn truncate_graphemes(&mut self, n: usize) {
+        debug!("Truncate graphemes: {}", n);
+        if let Some((idx, _)) = self.as_str().grapheme_indices(true).nth(n) {
+            self.size = idx.into_lossy();
+        }
+    }
+
+    /// Truncates `ArrayString` at the last char boundary that leaves room for `suffix` and appends it, so the result plus the suffix always fits in [`capacity`]
+    ///
+    /// Returns error and leaves the string unchanged if `suffix` alone is bigger than [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let title = "A very long title for a small label";
+    /// let mut label = ArrayString::<12>::from_str_truncate(title);
+    /// if label.len() < title.len() {
+    ///     label.truncate_with_suffix("…")?;
+    /// }
+    /// assert_eq!(label.as_str(), "A very lo…");
+    ///
+    /// let mut s = ArrayString::<12>::try_from_str("Olá🤔🤔")?;
+    /// s.truncate_with_suffix("...")?;
+    /// assert_eq!(s.as_str(), "Olá🤔...");
+    ///
+    /// assert!(s.truncate_with_suffix("0".repeat(13)).is_err());
+    /// assert_eq!(s.as_str(), "Olá🤔...");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate_with_suffix(&mut self, suffix: impl AsRef<str>) -> Result<(), OutOfBounds> {
+        let suffix = suffix.as_ref();
+        debug!("Truncate with suffix: {}", suffix);
+        is_inside_boundary(suffix.len(), Self::capacity())?;
+        let kept = truncate_str(self.as_str(), Self::capacity() - suffix.len()).len();
+        self.size = kept.into_lossy();
+        // Safety: `kept + suffix.len()` fits in capacity and `kept` is a char boundary
+        unsafe { self.push_str_unchecked(suffix) };
+        Ok(())
+    }
+
+    /// Removes last character from `ArrayString`, if any.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("A🤔")?;
+    /// assert_eq!(s.pop(), Some('🤔'));
+    /// assert_eq!(s.pop(), Some('A'));
+    /// assert_eq!(s.pop(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Option<char> {
+        debug!("Pop");
+        let ch = self.as_str().chars().last()?;
+        self.size = (self.len() - ch.len_utf8()).into_lossy();
+        Some(ch)
+    }
+
+    /// Removes whitespaces from the beggining and end of the string
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<255>::try_from_str("   to be trimmed     ")?;
+    /// string.trim();
+    /// assert_eq!(string.as_str(), "to be trimmed");
+    ///
+    /// let mut string = ArrayString::<23>::try_from_str("   🤔")?;
+    /// string.trim();
+    /// assert_eq!(string.as_str(), "🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim(&mut self) {
+        trace!("Trim: {self:?}");
+        self.trim_matches(char::is_whitespace);
+    }
+
+    /// Removes whitespaces from the beggining of the string
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<255>::try_from_str("   to be trimmed     ")?;
+    /// string.trim_start();
+    /// assert_eq!(string.as_str(), "to be trimmed     ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim_start(&mut self) {
+        trace!("Trim start: {self:?}");
+        self.trim_start_matches(char::is_whitespace);
+    }
+
+    /// Removes whitespaces from the end of the string
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<255>::try_from_str("   to be trimmed     ")?;
+    /// string.trim_end();
+    /// assert_eq!(string.as_str(), "   to be trimmed");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim_end(&mut self) {
+        trace!("Trim end: {self:?}");
+        self.trim_end_matches(char::is_whitespace);
+    }
+
+    /// Repeatedly removes every match of the [`Pattern`] from the beggining and end of the string
+    ///
+    /// [`Pattern`]: ./trait.Pattern.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<23>::try_from_str("--🤔-a-b--")?;
+    /// string.trim_matches('-');
+    /// assert_eq!(string.as_str(), "🤔-a-b");
+    ///
+    /// let mut string = ArrayString::<23>::try_from_str("12abc34")?;
+    /// string.trim_matches(|c: char| c.is_ascii_digit());
+    /// assert_eq!(string.as_str(), "abc");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim_matches(&mut self, mut pat: impl Pattern) {
+        trace!("Trim matches: {self:?}");
+        let start = self.matches_at_start(&mut pat);
+        let end = self.matches_at_end(&mut pat, start);
+        self.keep_range(start..end);
+    }
+
+    /// Repeatedly removes every match of the [`Pattern`] from the beggining of the string
+    ///
+    /// [`Pattern`]: ./trait.Pattern.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<23>::try_from_str("abab🤔ab")?;
+    /// string.trim_start_matches("ab");
+    /// assert_eq!(string.as_str(), "🤔ab");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim_start_matches(&mut self, mut pat: impl Pattern) {
+        trace!("Trim start matches: {self:?}");
+        let start = self.matches_at_start(&mut pat);
+        self.keep_range(start..self.len());
+    }
+
+    /// Repeatedly removes every match of the [`Pattern`] from the end of the string
+    ///
+    /// [`Pattern`]: ./trait.Pattern.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<23>::try_from_str("ab🤔abab")?;
+    /// string.trim_end_matches("ab");
+    /// assert_eq!(string.as_str(), "ab🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim_end_matches(&mut self, mut pat: impl Pattern) {
+        trace!("Trim end matches: {self:?}");
+        let end = self.matches_at_end(&mut pat, 0);
+        self.keep_range(0..end);
+    }
+
+    /// Removes one match of the [`Pattern`] from the beggining of the string, returns whether something was removed
+    ///
+    /// [`Pattern`]: ./trait.Pattern.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<23>::try_from_str("https://https://a.b")?;
+    /// assert!(string.strip_prefix_in_place("https://"));
+    /// assert_eq!(string.as_str(), "https://a.b");
+    /// assert!(!string.strip_prefix_in_place("ftp://"));
+    /// assert_eq!(string.as_str(), "https://a.b");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn strip_prefix_in_place(&mut self, mut pat: impl Pattern) -> bool {
+        trace!("Strip prefix: {self:?}");
+        let Some(start) = pat.prefix_len_in(self.as_str()) else {
+            return false;
+        };
+        self.keep_range(start..self.len());
+        true
+    }
+
+    /// Removes one match of the [`Pattern`] from the end of the string, returns whether something was removed
+    ///
+    /// [`Pattern`]: ./trait.Pattern.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = ArrayString::<23>::try_from_str("file.tar.gz")?;
+    /// assert!(string.strip_suffix_in_place(".gz"));
+    /// assert_eq!(string.as_str(), "file.tar");
+    /// assert!(!string.strip_suffix_in_place(".gz"));
+    /// assert_eq!(string.as_str(), "file.tar");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn strip_suffix_in_place(&mut self, mut pat: impl Pattern) -> bool {
+        trace!("Strip suffix: {self:?}");
+        let Some(len) = pat.suffix_len_in(self.as_str()) else {
+            return false;
+        };
+        self.keep_range(0..self.len().saturating_sub(len));
+        true
+    }
+
+    /// Byte length of the consecutive matches at the start of the string, stops at empty matches
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn matches_at_start(&self, pat: &mut impl Pattern) -> usize {
+        let mut start = 0;
+        while let Some(len) = self
+            .as_str()
+            .get(start..)
+            .and_then(|s| pat.prefix_len_in(s))
+        {
+            if len == 0 {
+                break;
+            }
+            start += len;
+        }
+        start
+    }
+
+    /// End of the string after removing consecutive matches from the end, without going before `start`
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn matches_at_end(&self, pat: &mut impl Pattern, start: usize) -> usize {
+        let mut end = self.len();
+        while let Some(len) = self
+            .as_str()
+            .get(start..end)
+            .and_then(|s| pat.suffix_len_in(s))
+        {
+            if len == 0 {
+                break;
+            }
+            end -= len;
+        }
+        end
+    }
+
+    /// Keeps only the bytes in `range`, which must be within char boundaries, moving them to the start with a single memmove
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn keep_range(&mut self, range: Range<usize>) {
+        debug_assert!(range.start <= range.end && range.end <= self.len());
+        debug_assert!(self.as_str().get(range.clone()).is_some());
+        let len = range.end.saturating_sub(range.start);
+        if range.start > 0 && len > 0 {
+            let ptr = self.array.as_mut_ptr();
+            // Safety: both ranges are inside the initialized content, `ptr::copy` handles the overlap
+            unsafe { core::ptr::copy(ptr.add(range.start), ptr, len) };
+        }
+        self.size = len.into_lossy();
+    }
+
+    /// Creates new `ArrayString` with the uppercase equivalent of this one, returning [`OutOfBounds`] if it's bigger than [`capacity`]
+    ///
+    /// Case mapping may change the length, like `ŉ` becoming `ʼN`. Chars are mapped one by one, like `char::to_uppercase`
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("straße")?;
+    /// assert_eq!(s.try_to_uppercase()?, "STRASSE");
+    ///
+    /// let s = ArrayString::<2>::try_from_str("ŉ")?;
+    /// assert!(matches!(s.try_to_uppercase(), Err(OutOfBounds { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_to_uppercase(&self) -> Result<Self, OutOfBounds> {
+        trace!("Try to uppercase: {self}");
+        if self.is_ascii() {
+            return Ok(self.to_ascii_uppercase());
+        }
+        Self::try_from_chars(self.chars().flat_map(char::to_uppercase))
+    }
+
+    /// Creates new `ArrayString` with the uppercase equivalent of this one, truncating at the last char that fits in [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<2>::try_from_str("ŉ")?;
+    /// assert_eq!(s.to_uppercase_truncate(), "ʼ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn to_uppercase_truncate(&self) -> Self {
+        trace!("To uppercase truncate: {self}");
+        if self.is_ascii() {
+            return self.to_ascii_uppercase();
+        }
+        Self::from_chars_truncate(self.chars().flat_map(char::to_uppercase))
+    }
+
+    /// Creates new `ArrayString` with the lowercase equivalent of this one, returning [`OutOfBounds`] if it's bigger than [`capacity`]
+    ///
+    /// Case mapping may change the length, like `İ` becoming `i̇`. Chars are mapped one by one, like `char::to_lowercase`, so a final `Σ` becomes `σ`, not `ς` as in `str::to_lowercase`
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("User@Example.COM")?;
+    /// assert_eq!(s.try_to_lowercase()?, "user@example.com");
+    ///
+    /// let s = ArrayString::<4>::try_from_str("İİ")?;
+    /// assert!(matches!(s.try_to_lowercase(), Err(OutOfBounds { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_to_lowercase(&self) -> Result<Self, OutOfBounds> {
+        trace!("Try to lowercase: {self}");
+        if self.is_ascii() {
+            return Ok(self.to_ascii_lowercase());
+        }
+        Self::try_from_chars(self.chars().flat_map(char::to_lowercase))
+    }
+
+    /// Creates new `ArrayString` with the lowercase equivalent of this one, truncating at the last char that fits in [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<4>::try_from_str("İİ")?;
+    /// assert_eq!(s.to_lowercase_truncate(), "i̇i");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn to_lowercase_truncate(&self) -> Self {
+        trace!("To lowercase truncate: {self}");
+        if self.is_ascii() {
+            return self.to_ascii_lowercase();
+        }
+        Self::from_chars_truncate(self.chars().flat_map(char::to_lowercase))
+    }
+
+    /// Creates new `ArrayString` with ASCII letters converted to uppercase, leaving other chars unchanged
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// const LOUD: ArrayString<10> = ArrayString::from_static("grüße").to_ascii_uppercase();
+    /// assert_eq!(LOUD, "GRüßE");
+    /// ```
+    #[inline]
+    pub const fn to_ascii_uppercase(&self) -> Self {
+        let mut out = *self;
+        out.make_ascii_uppercase();
+        out
+    }
+
+    /// Creates new `ArrayString` with ASCII letters converted to lowercase, leaving other chars unchanged
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// const DOMAIN: ArrayString<16> = ArrayString::from_static("Example.COM").to_ascii_lowercase();
+    /// assert_eq!(DOMAIN, "example.com");
+    /// ```
+    #[inline]
+    pub const fn to_ascii_lowercase(&self) -> Self {
+        let mut out = *self;
+        out.make_ascii_lowercase();
+        out
+    }
+
+    /// Converts ASCII letters to uppercase in place, leaving other chars unchanged
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("grüße")?;
+    /// s.make_ascii_uppercase();
+    /// assert_eq!(s, "GRüßE");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn make_ascii_uppercase(&mut self) {
+        let mut i = 0;
+        while i < self.len() {
+            // ASCII case changes never touch multi-byte chars, so the string stays valid utf-8
+            self.array[i] = self.array[i].to_ascii_uppercase();
+            i += 1;
+        }
+    }
+
+    /// Converts ASCII letters to lowercase in place, leaving other chars unchanged
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("GRÜßE")?;
+    /// s.make_ascii_lowercase();
+    /// assert_eq!(s, "grÜße");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn make_ascii_lowercase(&mut self) {
+        let mut i = 0;
+        while i < self.len() {
+            // ASCII case changes never touch multi-byte chars, so the string stays valid utf-8
+            self.array[i] = self.array[i].to_ascii_lowercase();
+            i += 1;
+        }
+    }
+
+    /// Removes specified char from `ArrayString`
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
+    /// assert!(matches!(s.remove("ABCD🤔".len()), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.remove(10), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.remove(6), Err(Error::NotCharBoundary(_))));
+    /// assert_eq!(s.remove(0), Ok('A'));
+    /// assert_eq!(s.as_str(), "BCD🤔");
+    /// assert_eq!(s.remove(2), Ok('D'));
+    /// assert_eq!(s.as_str(), "BC🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
         debug!("Remove: {}", idx);
         is_inside_boundary(idx.saturating_add(1), self.len())?;
         is_char_boundary(self, idx)?;
@@ -571,41 +1984,175 @@ where
         let ch = self
             .as_str()
             .get(idx..end)
-            .ok_or(Error::OutOfBounds)?
+            .ok_or(OutOfBounds::new(end, self.len()))?
             .chars()
             .next()
-            .ok_or(Error::Utf8)?;
+            .ok_or(NotCharBoundary::new(idx))?;
         self.replace_range(idx..idx + ch.len_utf8(), "")?;
         Ok(ch)
     }
 
-    /// Retains only the characters specified by the predicate.
+    /// Retains only the characters specified by the predicate.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
+    /// s.retain(|c| c != '🤔');
+    /// assert_eq!(s.as_str(), "ABCD");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        trace!("Retain");
+        self.retain_indices(|_, ch| f(ch));
+    }
+
+    /// Retains only the characters specified by the predicate, that also receives the char's byte index in the original string.
+    ///
+    /// Kept chars are moved left in a single pass, without re-encoding them.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("A🤔BCD")?;
+    /// s.retain_indices(|idx, _| idx % 2 == 0);
+    /// assert_eq!(s.as_str(), "AC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn retain_indices(&mut self, mut f: impl FnMut(usize, char) -> bool) {
+        trace!("Retain indices");
+        let len = self.len();
+        // Chars are moved while the string is empty, so a panic in `f` can't expose it half compacted
+        self.size = L::ZERO;
+        let (mut read, mut write) = (0, 0);
+        while read < len {
+            // Safety: `write <= read` and nothing is written after `read + ch.len_utf8()`,
+            // so `read..len` still holds the original valid utf-8, and `read` is always a char boundary
+            let rest = unsafe { str::from_utf8_unchecked(self.array.get_unchecked(read..len)) };
+            let Some(ch) = rest.chars().next() else {
+                break;
+            };
+            if f(read, ch) {
+                if read != write {
+                    // Safety: both ranges are inside the array, since `write <= read` and `read + ch.len_utf8() <= len`
+                    unsafe {
+                        let ptr = self.array.as_mut_ptr();
+                        core::ptr::copy(ptr.add(read), ptr.add(write), ch.len_utf8());
+                    }
+                }
+                write += ch.len_utf8();
+            }
+            read += ch.len_utf8();
+        }
+        self.size = write.into_lossy();
+    }
+
+    /// Retains only the characters specified by the predicate, that can also change them.
+    ///
+    /// Changed chars may have a different length, returns [`OutOfBounds`] if the result is bigger than [`capacity`], keeping only the chars that fit before it.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<5>::try_from_str("a-b_c")?;
+    /// s.retain_mut(|ch| {
+    ///     *ch = ch.to_ascii_uppercase();
+    ///     ch.is_alphabetic()
+    /// })?;
+    /// assert_eq!(s.as_str(), "ABC");
+    ///
+    /// assert!(matches!(s.retain_mut(|ch| { *ch = '🤔'; true }), Err(OutOfBounds { .. })));
+    /// assert_eq!(s.as_str(), "🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut char) -> bool) -> Result<(), OutOfBounds> {
+        trace!("Retain mut");
+        let len = self.len();
+        // Chars are moved while the string is empty, so a panic in `f` can't expose it half rewritten
+        self.size = L::ZERO;
+        // Holds the input left to read, once a changed char would overwrite it
+        let mut copy: Option<Self> = None;
+        let (mut read, mut write, mut required) = (0, 0, 0);
+        while read < len {
+            let source = copy.as_ref().map_or(&self.array, |copy| &copy.array);
+            // Safety: nothing is written after `read` in `source`, so `read..len` still holds the original valid utf-8,
+            // and `read` is always a char boundary
+            let rest = unsafe { str::from_utf8_unchecked(source.get_unchecked(read..len)) };
+            let Some(mut ch) = rest.chars().next() else {
+                break;
+            };
+            read += ch.len_utf8();
+            if !f(&mut ch) {
+                continue;
+            }
+            required += ch.len_utf8();
+            if required > N {
+                continue;
+            }
+            if copy.is_none() && required > read {
+                copy = Some(*self);
+            }
+            let mut buf = [0; 4];
+            let encoded = ch.encode_utf8(&mut buf);
+            // Safety: `write + encoded.len() == required <= N`, and it ends before the input left to read in `source`
+            unsafe {
+                let ptr = self.array.as_mut_ptr().add(write);
+                core::ptr::copy_nonoverlapping(encoded.as_ptr(), ptr, encoded.len());
+            }
+            write = required;
+        }
+        self.size = write.into_lossy();
+        is_inside_boundary(required, N)
+    }
+
+    /// Removes consecutive chars for which `same(char, previous)` returns true, where `previous` is the last char kept.
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
-    /// s.retain(|c| c != '🤔');
-    /// assert_eq!(s.as_str(), "ABCD");
+    /// let mut s = ArrayString::<23>::try_from_str("a  \t b\n\nc")?;
+    /// s.dedup_by(|ch, previous| ch.is_whitespace() && previous.is_whitespace());
+    /// assert_eq!(s.as_str(), "a b\nc");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
-        trace!("Retain");
-        // Not the most efficient solution, we could shift left during batch mismatch
-        *self = Self::from_chars_truncate(self.as_str().chars().filter(|c| f(*c)));
+    pub fn dedup_by(&mut self, mut same: impl FnMut(char, char) -> bool) {
+        trace!("Dedup by");
+        let mut previous = None;
+        self.retain(|ch| {
+            let keep = !matches!(previous, Some(previous) if same(ch, previous));
+            if keep {
+                previous = Some(ch);
+            }
+            keep
+        });
     }
 
     /// Inserts character at specified index, returning error if total length is bigger than [`capacity`].
     ///
-    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`NotCharBoundary`] if `idx` is not a char position
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
@@ -615,11 +2162,11 @@ where
     /// s.try_insert(1, 'A')?;
     /// s.try_insert(2, 'B')?;
     /// assert_eq!(s.as_str(), "AABBCD🤔");
-    /// assert_eq!(s.try_insert(20, 'C'), Err(Error::OutOfBounds));
-    /// assert_eq!(s.try_insert(8, 'D'), Err(Error::Utf8));
+    /// assert!(matches!(s.try_insert(20, 'C'), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.try_insert(8, 'D'), Err(Error::NotCharBoundary(_))));
     ///
     /// let mut s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
-    /// assert_eq!(s.try_insert(0, 'C'), Err(Error::OutOfBounds));
+    /// assert!(matches!(s.try_insert(0, 'C'), Err(Error::OutOfBounds(_))));
     /// # Ok(())
     /// # }
     /// ```
@@ -630,14 +2177,45 @@ where
         self.try_insert_str(idx, ch.encode_utf8(&mut buf))
     }
 
+    /// Inserts character at specified index assuming length is appropriate
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `idx` does not lie on a utf-8 `char` boundary, if `idx` > `self.len()`
+    /// or if `self.len() + ch.len_utf8()` > [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
+    /// unsafe { s.insert_unchecked(1, 'A') };
+    /// unsafe { s.insert_unchecked(1, 'B') };
+    /// assert_eq!(s.as_str(), "ABABCD🤔");
+    ///
+    /// // Undefined behavior, don't do it
+    /// // s.insert_unchecked(20, 'C');
+    /// // s.insert_unchecked(8, 'D');
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn insert_unchecked(&mut self, idx: usize, ch: char) {
+        let mut buf = [0; 4];
+        self.insert_str_unchecked(idx, ch.encode_utf8(&mut buf));
+    }
+
     /// Inserts string slice at specified index, returning error if total length is bigger than [`capacity`].
     ///
     /// Returns [`OutOfBounds`] if `idx` is out of bounds
-    /// Returns [`Utf8`] if `idx` is not a char position
+    /// Returns [`NotCharBoundary`] if `idx` is not a char position
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
@@ -646,10 +2224,10 @@ where
     /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
     /// s.try_insert_str(1, "AB")?;
     /// s.try_insert_str(1, "BC")?;
-    /// assert_eq!(s.try_insert_str(1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds));
+    /// assert!(matches!(s.try_insert_str(1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds(_))));
     /// assert_eq!(s.as_str(), "ABCABBCD🤔");
-    /// assert_eq!(s.try_insert_str(20, "C"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.try_insert_str(10, "D"), Err(Error::Utf8));
+    /// assert!(matches!(s.try_insert_str(20, "C"), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.try_insert_str(10, "D"), Err(Error::NotCharBoundary(_))));
     /// # Ok(())
     /// # }
     /// ```
@@ -663,11 +2241,11 @@ where
 
     /// Inserts string slice at specified index, truncating size if bigger than [`capacity`].
     ///
-    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`NotCharBoundary`] if `idx` is not a char position
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
@@ -678,8 +2256,8 @@ where
     /// s.insert_str_truncate(1, "BC")?;
     /// assert_eq!(s.as_str(), "ABCABBCD🤔");
     ///
-    /// assert_eq!(s.insert_str_truncate(20, "C"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.insert_str_truncate(10, "D"), Err(Error::Utf8));
+    /// assert!(matches!(s.insert_str_truncate(20, "C"), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.insert_str_truncate(10, "D"), Err(Error::NotCharBoundary(_))));
     ///
     /// s.clear();
     /// s.insert_str_truncate(0, "0".repeat(ArrayString::<23>::capacity() + 10))?;
@@ -702,6 +2280,85 @@ where
         Ok(())
     }
 
+    /// Inserts the biggest prefix of the string slice that fits at specified index, cut at a char boundary, returning the tail that didn't fit (empty if everything fit)
+    ///
+    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`NotCharBoundary`] if `idx` is not a char position, nothing is inserted in that case
+    ///
+    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<7>::try_from_str("AB")?;
+    /// assert_eq!(s.try_insert_str_partial(1, "CD🤔EF")?, "🤔EF");
+    /// assert_eq!(s.as_str(), "ACDB");
+    /// assert_eq!(s.try_insert_str_partial(0, "É")?, "");
+    /// assert_eq!(s.as_str(), "ÉACDB");
+    ///
+    /// assert!(matches!(s.try_insert_str_partial(20, "C"), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.try_insert_str_partial(1, "D"), Err(Error::NotCharBoundary(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_insert_str_partial<'a>(
+        &mut self,
+        idx: usize,
+        string: &'a str,
+    ) -> Result<&'a str, Error> {
+        trace!("Insert str partial at {idx}: {string} to {self}");
+        let fits = truncate_str(string, Self::capacity().saturating_sub(self.len()));
+        self.try_insert_str(idx, fits)?;
+        Ok(string.get(fits.len()..).unwrap_or_default())
+    }
+
+    /// Inserts string slice at specified index, assuming total length is appropriate.
+    ///
+    /// # Safety
+    ///
+    /// It's UB if `idx` does not lie on a utf-8 `char` boundary, if `idx` > `self.len()`
+    /// or if `self.len() + string.len()` > [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
+    /// unsafe { s.insert_str_unchecked(1, "AB") };
+    /// unsafe { s.insert_str_unchecked(1, "BC") };
+    /// assert_eq!(s.as_str(), "ABCABBCD🤔");
+    ///
+    /// // Undefined behavior, don't do it
+    /// // unsafe { s.insert_str_unchecked(20, "C") };
+    /// // unsafe { s.insert_str_unchecked(10, "D") };
+    /// // unsafe { s.insert_str_unchecked(1, "0".repeat(ArrayString::<23>::capacity())) };
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn insert_str_unchecked(&mut self, idx: usize, string: impl AsRef<str>) {
+        let str = string.as_ref().as_bytes();
+        trace!(
+            "Insert str unchecked at {idx}: {} to {self}",
+            string.as_ref()
+        );
+        debug_assert!(idx <= self.len());
+        debug_assert!(self.as_str().is_char_boundary(idx));
+        debug_assert!(self.len() + str.len() <= Self::capacity());
+        // Safety: the caller guarantees that `idx` is a char boundary inside the string
+        // and that `str` fits in the available space (`Self::capacity() - self.len()`)
+        let ptr = self.array.as_mut_ptr().add(idx);
+        core::ptr::copy(ptr, ptr.add(str.len()), self.len() - idx);
+        core::ptr::copy_nonoverlapping(str.as_ptr(), ptr, str.len());
+        self.size = (self.len() + str.len()).into_lossy();
+    }
+
     /// Returns `ArrayString` length.
     ///
     /// ```rust
@@ -717,18 +2374,37 @@ where
     /// # }
     /// ```
     #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn len(&self) -> usize {
-        trace!("Len");
-        self.size as usize
+    pub const fn len(&self) -> usize {
+        length_to_usize(self.size)
+    }
+
+    /// Checks if `ArrayString` is empty.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ABCD")?;
+    /// assert!(!s.is_empty());
+    /// s.clear();
+    /// assert!(s.is_empty());
+    ///
+    /// const EMPTY: bool = ArrayString::<23>::from_static("").is_empty();
+    /// assert!(EMPTY);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Splits `ArrayString` in two if `at` is smaller than `self.len()`.
     ///
-    /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
+    /// Returns [`NotCharBoundary`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
     ///
     /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
     ///
     /// ```rust
     /// # use arraystring::{Error, prelude::*};
@@ -737,8 +2413,8 @@ where
     /// let mut s = ArrayString::<23>::try_from_str("AB🤔CD")?;
     /// assert_eq!(s.split_off(6)?.as_str(), "CD");
     /// assert_eq!(s.as_str(), "AB🤔");
-    /// assert_eq!(s.split_off(20), Err(Error::OutOfBounds));
-    /// assert_eq!(s.split_off(4), Err(Error::Utf8));
+    /// assert!(matches!(s.split_off(20), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.split_off(4), Err(Error::NotCharBoundary(_))));
     /// # Ok(())
     /// # }
     /// ```
@@ -750,6 +2426,106 @@ where
         Ok(drained)
     }
 
+    /// Splits `ArrayString` at every match of `pat`, copying each piece to a new `ArrayString<M>` in a collection of up to `K` parts. Empty patterns match between every char, like `str::split`.
+    ///
+    /// Returns [`OutOfBounds`] if there are more than `K` pieces or if a piece is bigger than `M`
+    ///
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<32>::try_from_str("key=value;k=v;a=")?;
+    /// let parts = s.split_into::<9, 4>(';')?;
+    /// assert_eq!(parts.as_slice(), ["key=value", "k=v", "a="]);
+    ///
+    /// assert!(matches!(s.split_into::<9, 2>(';'), Err(OutOfBounds { .. })));
+    /// assert!(matches!(s.split_into::<8, 4>(';'), Err(OutOfBounds { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn split_into<const M: usize, const K: usize>(
+        &self,
+        mut pat: impl Pattern,
+    ) -> Result<Parts<M, K, L>, OutOfBounds> {
+        debug!("Split into {K} parts of {M}: {self}");
+        let string = self.as_str();
+        let mut parts = Parts::new();
+        let (mut start, mut search) = (0, 0);
+        while let Some(found) = string.get(search..).and_then(|rest| pat.find_in(rest)) {
+            let (end, next) = (search + found.start, search + found.end);
+            parts.try_push(string.get(start..end).unwrap_or_default())?;
+            start = next;
+            search = next;
+            if found.is_empty() {
+                // Empty matches move forward one char, so they aren't found again
+                let rest = string.get(next..).unwrap_or_default();
+                search += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        parts.try_push(string.get(start..).unwrap_or_default())?;
+        Ok(parts)
+    }
+
+    /// Splits `ArrayString` at the first match of `pat`, returning the pieces before and after it, or `None` if there is no match
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("key=value=1")?;
+    /// let (key, value) = s.split_once_owned('=').unwrap();
+    /// assert_eq!(key, "key");
+    /// assert_eq!(value, "value=1");
+    /// assert_eq!(s.split_once_owned(';'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn split_once_owned(&self, mut pat: impl Pattern) -> Option<(Self, Self)> {
+        debug!("Split once: {self}");
+        let found = pat.find_in(self.as_str())?;
+        Some(self.split_around(found))
+    }
+
+    /// Splits `ArrayString` at the last match of `pat`, returning the pieces before and after it, or `None` if there is no match
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("key=value=1")?;
+    /// let (key, value) = s.rsplit_once_owned('=').unwrap();
+    /// assert_eq!(key, "key=value");
+    /// assert_eq!(value, "1");
+    /// assert_eq!(s.rsplit_once_owned(';'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn rsplit_once_owned(&self, mut pat: impl Pattern) -> Option<(Self, Self)> {
+        debug!("Rsplit once: {self}");
+        let found = pat.rfind_in(self.as_str())?;
+        Some(self.split_around(found))
+    }
+
+    /// Copies the pieces before and after `range` to new strings, they always fit since they are smaller than `self`
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn split_around(&self, range: Range<usize>) -> (Self, Self) {
+        let before = self.as_str().get(..range.start).unwrap_or_default();
+        let after = self.as_str().get(range.end..).unwrap_or_default();
+        (
+            Self::from_str_truncate(before),
+            Self::from_str_truncate(after),
+        )
+    }
+
     /// Empties `ArrayString`
     ///
     /// ```rust
@@ -767,7 +2543,7 @@ where
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     pub fn clear(&mut self) {
         trace!("Clear");
-        self.size = 0;
+        self.size = L::ZERO;
     }
 
     /// Creates a draining iterator that removes the specified range in the `ArrayString` and yields the removed chars.
@@ -782,14 +2558,15 @@ where
     /// assert_eq!(s.drain(..3)?.collect::<Vec<_>>(), vec!['A', 'B', 'C']);
     /// assert_eq!(s.as_str(), "D🤔");
     ///
-    /// assert_eq!(s.drain(3..), Err(Error::Utf8));
-    /// assert_eq!(s.drain(10..), Err(Error::OutOfBounds));
+    /// assert!(matches!(s.drain(3..), Err(Error::NotCharBoundary(_))));
+    /// assert!(matches!(s.drain(10..), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.drain(3..1), Err(Error::InvalidRange(_))));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N>, Error> {
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N, L>, Error> {
         let start = match range.start_bound() {
             Bound::Included(t) => *t,
             Bound::Excluded(t) => t.saturating_add(1),
@@ -802,8 +2579,9 @@ where
         };
 
         debug!("Drain iterator (len: {}): {start}..{end}", self.len());
-        is_inside_boundary(start, end)?;
+        is_inside_boundary(start, self.len())?;
         is_inside_boundary(end, self.len())?;
+        is_valid_range(start, end)?;
         is_char_boundary(self, start)?;
         is_char_boundary(self, end)?;
         debug_assert!(start <= end && end <= self.len());
@@ -829,9 +2607,10 @@ where
     /// s.replace_range(2..4, "EFGHI")?;
     /// assert_eq!(s, "ABEFGHI🤔");
     ///
-    /// assert_eq!(s.replace_range(9.., "J"), Err(Error::Utf8));
-    /// assert_eq!(s.replace_range(..90, "K"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.replace_range(0..1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds));
+    /// assert!(matches!(s.replace_range(9.., "J"), Err(Error::NotCharBoundary(_))));
+    /// assert!(matches!(s.replace_range(..90, "K"), Err(Error::OutOfBounds(_))));
+    /// assert!(matches!(s.replace_range(3..1, "K"), Err(Error::InvalidRange(_))));
+    /// assert!(matches!(s.replace_range(0..1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds(_))));
     /// # Ok(())
     /// # }
     /// ```
@@ -864,12 +2643,13 @@ where
         if start == end && str.is_empty() {
             return Ok(());
         }
-        is_inside_boundary(start, end)?;
+        is_inside_boundary(start, self.len())?;
         is_inside_boundary(end, self.len())?;
+        is_valid_range(start, end)?;
         is_inside_boundary(str.len(), Self::capacity())?;
         is_char_boundary(self, start)?;
         is_char_boundary(self, end)?;
-        // Will never overflow since start < end and str.len() cannot be bigger than 255
+        // Will never overflow since start < end and str.len() cannot be bigger than capacity
         is_inside_boundary(self.len() + str.len() + start - end, Self::capacity())?;
 
         let ptr = self.array.as_mut_ptr();
@@ -890,265 +2670,360 @@ where
         self.size = (self.len() + str.len() + start - end).into_lossy();
         Ok(())
     }
+
+    /// Replaces every match of `pat` with `with`, in place. Empty patterns match between every char, like `str::replace`.
+    ///
+    /// Returns [`OutOfBounds`] if the result doesn't fit in [`capacity`], leaving the string unchanged.
+    ///
+    /// The string is searched twice, first to check the result fits, so closures must match the same chars both times.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("key=value;key=value")?;
+    /// s.replace_in_place("key", "k")?;
+    /// s.replace_in_place(';', "; ")?;
+    /// assert_eq!(s, "k=value; k=value");
+    ///
+    /// assert!(matches!(s.replace_in_place('=', "====="), Err(OutOfBounds { .. })));
+    /// assert_eq!(s, "k=value; k=value");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn replace_in_place(
+        &mut self,
+        pat: impl Pattern,
+        with: impl AsRef<str>,
+    ) -> Result<(), OutOfBounds> {
+        self.replacen_in_place(pat, with, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of `pat` with `with`, in place. Empty patterns match between every char, like `str::replacen`.
+    ///
+    /// Returns [`OutOfBounds`] if the result doesn't fit in [`capacity`], leaving the string unchanged.
+    ///
+    /// The string is searched twice, first to check the result fits, so closures must match the same chars both times.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("a1b2c3")?;
+    /// s.replacen_in_place(|ch: char| ch.is_ascii_digit(), "", 2)?;
+    /// assert_eq!(s, "abc3");
+    ///
+    /// assert!(matches!(s.replacen_in_place("", "0".repeat(10), 3), Err(OutOfBounds { .. })));
+    /// assert_eq!(s, "abc3");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn replacen_in_place(
+        &mut self,
+        mut pat: impl Pattern,
+        with: impl AsRef<str>,
+        count: usize,
+    ) -> Result<(), OutOfBounds> {
+        let with = with.as_ref();
+        debug!("Replace {count} matches with {with} in {self}");
+        let len = self.len();
+        // First pass finds the final length, and how far the output gets ahead of the input
+        let (mut matches, mut required, mut ahead) = (0, len, 0);
+        let mut rest = self.as_str();
+        while matches < count {
+            let Some(found) = pat.find_in(rest) else {
+                break;
+            };
+            matches += 1;
+            required = required + with.len() - found.len();
+            ahead = ahead.max(required.saturating_sub(len));
+            rest = rest.get(found.end..).unwrap_or_default();
+            if found.is_empty() {
+                // Empty matches move forward one char, so they aren't found again
+                let Some(ch) = rest.chars().next() else {
+                    break;
+                };
+                rest = rest.get(ch.len_utf8()..).unwrap_or_default();
+            }
+        }
+        is_inside_boundary(required, N)?;
+        if matches == 0 {
+            return Ok(());
+        }
+
+        // The input is read from a copy only if moving it forward can't keep it ahead of the output
+        let original = if len + ahead > N { Some(*self) } else { None };
+        let dst = self.array.as_mut_ptr();
+        let (src, mut read, end) = match &original {
+            Some(original) => (original.array.as_ptr(), 0, len),
+            None => {
+                // Safety: `len + ahead <= N`
+                unsafe { core::ptr::copy(dst, dst.add(ahead), len) };
+                (dst.cast_const(), ahead, ahead + len)
+            }
+        };
+        let mut written = 0;
+        for _ in 0..matches {
+            // Safety: `read..end` is the unread part of the original string, it always starts at a char boundary
+            let rest = unsafe {
+                str::from_utf8_unchecked(core::slice::from_raw_parts(src.add(read), end - read))
+            };
+            let Some(found) = pat.find_in(rest) else {
+                break;
+            };
+            let skip = match found.is_empty() {
+                true => rest.get(found.end..).and_then(|rest| rest.chars().next()),
+                false => None,
+            };
+            let skip = skip.map_or(0, char::len_utf8);
+            let next = read + found.end;
+            let limit = original.as_ref().map_or(next + skip, |_| N);
+            if written + found.start + with.len() + skip > limit {
+                // Only happens if the pattern doesn't match the same way it did in the first pass
+                break;
+            }
+            // Safety: the output ends before the unread input (or inside `N`, when reading from a copy), checked above
+            unsafe {
+                core::ptr::copy(src.add(read), dst.add(written), found.start);
+                written += found.start;
+                core::ptr::copy_nonoverlapping(with.as_ptr(), dst.add(written), with.len());
+                written += with.len();
+            }
+            read = next;
+            if found.is_empty() && skip == 0 {
+                break;
+            }
+            // Safety: `skip` bytes are left to read, and the output stays behind them (or inside `N`), checked above
+            unsafe { core::ptr::copy(src.add(read), dst.add(written), skip) };
+            (read, written) = (read + skip, written + skip);
+        }
+        // Safety: `read..end` is the unread part of the original string, it always starts at a char boundary
+        let rest = unsafe {
+            str::from_utf8_unchecked(core::slice::from_raw_parts(src.add(read), end - read))
+        };
+        let rest = truncate_str(rest, N - written);
+        // Safety: `rest` fits in the space left
+        unsafe { core::ptr::copy(rest.as_ptr(), dst.add(written), rest.len()) };
+        self.size = (written + rest.len()).into_lossy();
+        Ok(())
+    }
+
+    /// Creates new `ArrayString` with every match of `pat` replaced by `with`. Empty patterns match between every char, like `str::replace`.
+    ///
+    /// Returns [`OutOfBounds`] if the result doesn't fit in the new string's [`capacity`], it doesn't matter if the original would.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("my-long-identifier")?;
+    /// let replaced = s.replaced::<32>('-', "::")?;
+    /// assert_eq!(replaced, "my::long::identifier");
+    ///
+    /// assert_eq!(s.replaced::<16>("long-", "")?, "my-identifier");
+    /// assert!(matches!(s.replaced::<16>('-', "_"), Err(OutOfBounds { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn replaced<const M: usize>(
+        &self,
+        mut pat: impl Pattern,
+        with: impl AsRef<str>,
+    ) -> Result<ArrayString<M, L>, OutOfBounds> {
+        let with = with.as_ref();
+        debug!("Replaced with {with} in {self}");
+        let mut out = ArrayString::new();
+        let mut rest = self.as_str();
+        while let Some(found) = pat.find_in(rest) {
+            out.try_push_str(rest.get(..found.start).unwrap_or_default())?;
+            out.try_push_str(with)?;
+            rest = rest.get(found.end..).unwrap_or_default();
+            if found.is_empty() {
+                // Empty matches move forward one char, so they aren't found again
+                let Some(ch) = rest.chars().next() else {
+                    return Ok(out);
+                };
+                out.try_push(ch)?;
+                rest = rest.get(ch.len_utf8()..).unwrap_or_default();
+            }
+        }
+        out.try_push_str(rest)?;
+        Ok(out)
+    }
+}
+
+/// Consumes `reader` up to and including the next newline, returns how many bytes were skipped
+#[cfg(feature = "std")]
+#[inline]
+fn skip_line(reader: &mut impl io::BufRead) -> io::Result<usize> {
+    let mut skipped = 0;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let (len, found) = match available.iter().position(|byte| *byte == b'\n') {
+            Some(idx) => (idx + 1, true),
+            None => (available.len(), false),
+        };
+        reader.consume(len);
+        skipped += len;
+        if found || len == 0 {
+            return Ok(skipped);
+        }
+    }
 }
 
-/// Temporary hack until const generics constraints are stable
+/// Sealed traits, they can't be implemented outside of this crate
 pub(crate) mod sealed {
-    use super::*;
-    pub trait ValidCapacity {}
-    impl ValidCapacity for ArrayString<1> {}
-    impl ValidCapacity for ArrayString<2> {}
-    impl ValidCapacity for ArrayString<3> {}
-    impl ValidCapacity for ArrayString<4> {}
-    impl ValidCapacity for ArrayString<5> {}
-    impl ValidCapacity for ArrayString<6> {}
-    impl ValidCapacity for ArrayString<7> {}
-    impl ValidCapacity for ArrayString<8> {}
-    impl ValidCapacity for ArrayString<9> {}
-    impl ValidCapacity for ArrayString<10> {}
-    impl ValidCapacity for ArrayString<11> {}
-    impl ValidCapacity for ArrayString<12> {}
-    impl ValidCapacity for ArrayString<13> {}
-    impl ValidCapacity for ArrayString<14> {}
-    impl ValidCapacity for ArrayString<15> {}
-    impl ValidCapacity for ArrayString<16> {}
-    impl ValidCapacity for ArrayString<17> {}
-    impl ValidCapacity for ArrayString<18> {}
-    impl ValidCapacity for ArrayString<19> {}
-    impl ValidCapacity for ArrayString<20> {}
-    impl ValidCapacity for ArrayString<21> {}
-    impl ValidCapacity for ArrayString<22> {}
-    impl ValidCapacity for ArrayString<23> {}
-    impl ValidCapacity for ArrayString<24> {}
-    impl ValidCapacity for ArrayString<25> {}
-    impl ValidCapacity for ArrayString<26> {}
-    impl ValidCapacity for ArrayString<27> {}
-    impl ValidCapacity for ArrayString<28> {}
-    impl ValidCapacity for ArrayString<29> {}
-    impl ValidCapacity for ArrayString<30> {}
-    impl ValidCapacity for ArrayString<31> {}
-    impl ValidCapacity for ArrayString<32> {}
-    impl ValidCapacity for ArrayString<33> {}
-    impl ValidCapacity for ArrayString<34> {}
-    impl ValidCapacity for ArrayString<35> {}
-    impl ValidCapacity for ArrayString<36> {}
-    impl ValidCapacity for ArrayString<37> {}
-    impl ValidCapacity for ArrayString<38> {}
-    impl ValidCapacity for ArrayString<39> {}
-    impl ValidCapacity for ArrayString<40> {}
-    impl ValidCapacity for ArrayString<41> {}
-    impl ValidCapacity for ArrayString<42> {}
-    impl ValidCapacity for ArrayString<43> {}
-    impl ValidCapacity for ArrayString<44> {}
-    impl ValidCapacity for ArrayString<45> {}
-    impl ValidCapacity for ArrayString<46> {}
-    impl ValidCapacity for ArrayString<47> {}
-    impl ValidCapacity for ArrayString<48> {}
-    impl ValidCapacity for ArrayString<49> {}
-    impl ValidCapacity for ArrayString<50> {}
-    impl ValidCapacity for ArrayString<51> {}
-    impl ValidCapacity for ArrayString<52> {}
-    impl ValidCapacity for ArrayString<53> {}
-    impl ValidCapacity for ArrayString<54> {}
-    impl ValidCapacity for ArrayString<55> {}
-    impl ValidCapacity for ArrayString<56> {}
-    impl ValidCapacity for ArrayString<57> {}
-    impl ValidCapacity for ArrayString<58> {}
-    impl ValidCapacity for ArrayString<59> {}
-    impl ValidCapacity for ArrayString<60> {}
-    impl ValidCapacity for ArrayString<61> {}
-    impl ValidCapacity for ArrayString<62> {}
-    impl ValidCapacity for ArrayString<63> {}
-    impl ValidCapacity for ArrayString<64> {}
-    impl ValidCapacity for ArrayString<65> {}
-    impl ValidCapacity for ArrayString<66> {}
-    impl ValidCapacity for ArrayString<67> {}
-    impl ValidCapacity for ArrayString<68> {}
-    impl ValidCapacity for ArrayString<69> {}
-    impl ValidCapacity for ArrayString<70> {}
-    impl ValidCapacity for ArrayString<71> {}
-    impl ValidCapacity for ArrayString<72> {}
-    impl ValidCapacity for ArrayString<73> {}
-    impl ValidCapacity for ArrayString<74> {}
-    impl ValidCapacity for ArrayString<75> {}
-    impl ValidCapacity for ArrayString<76> {}
-    impl ValidCapacity for ArrayString<77> {}
-    impl ValidCapacity for ArrayString<78> {}
-    impl ValidCapacity for ArrayString<79> {}
-    impl ValidCapacity for ArrayString<80> {}
-    impl ValidCapacity for ArrayString<81> {}
-    impl ValidCapacity for ArrayString<82> {}
-    impl ValidCapacity for ArrayString<83> {}
-    impl ValidCapacity for ArrayString<84> {}
-    impl ValidCapacity for ArrayString<85> {}
-    impl ValidCapacity for ArrayString<86> {}
-    impl ValidCapacity for ArrayString<87> {}
-    impl ValidCapacity for ArrayString<88> {}
-    impl ValidCapacity for ArrayString<89> {}
-    impl ValidCapacity for ArrayString<90> {}
-    impl ValidCapacity for ArrayString<91> {}
-    impl ValidCapacity for ArrayString<92> {}
-    impl ValidCapacity for ArrayString<93> {}
-    impl ValidCapacity for ArrayString<94> {}
-    impl ValidCapacity for ArrayString<95> {}
-    impl ValidCapacity for ArrayString<96> {}
-    impl ValidCapacity for ArrayString<97> {}
-    impl ValidCapacity for ArrayString<98> {}
-    impl ValidCapacity for ArrayString<99> {}
-    impl ValidCapacity for ArrayString<100> {}
-    impl ValidCapacity for ArrayString<101> {}
-    impl ValidCapacity for ArrayString<102> {}
-    impl ValidCapacity for ArrayString<103> {}
-    impl ValidCapacity for ArrayString<104> {}
-    impl ValidCapacity for ArrayString<105> {}
-    impl ValidCapacity for ArrayString<106> {}
-    impl ValidCapacity for ArrayString<107> {}
-    impl ValidCapacity for ArrayString<108> {}
-    impl ValidCapacity for ArrayString<109> {}
-    impl ValidCapacity for ArrayString<110> {}
-    impl ValidCapacity for ArrayString<111> {}
-    impl ValidCapacity for ArrayString<112> {}
-    impl ValidCapacity for ArrayString<113> {}
-    impl ValidCapacity for ArrayString<114> {}
-    impl ValidCapacity for ArrayString<115> {}
-    impl ValidCapacity for ArrayString<116> {}
-    impl ValidCapacity for ArrayString<117> {}
-    impl ValidCapacity for ArrayString<118> {}
-    impl ValidCapacity for ArrayString<119> {}
-    impl ValidCapacity for ArrayString<120> {}
-    impl ValidCapacity for ArrayString<121> {}
-    impl ValidCapacity for ArrayString<122> {}
-    impl ValidCapacity for ArrayString<123> {}
-    impl ValidCapacity for ArrayString<124> {}
-    impl ValidCapacity for ArrayString<125> {}
-    impl ValidCapacity for ArrayString<126> {}
-    impl ValidCapacity for ArrayString<127> {}
-    impl ValidCapacity for ArrayString<128> {}
-    impl ValidCapacity for ArrayString<129> {}
-    impl ValidCapacity for ArrayString<130> {}
-    impl ValidCapacity for ArrayString<131> {}
-    impl ValidCapacity for ArrayString<132> {}
-    impl ValidCapacity for ArrayString<133> {}
-    impl ValidCapacity for ArrayString<134> {}
-    impl ValidCapacity for ArrayString<135> {}
-    impl ValidCapacity for ArrayString<136> {}
-    impl ValidCapacity for ArrayString<137> {}
-    impl ValidCapacity for ArrayString<138> {}
-    impl ValidCapacity for ArrayString<139> {}
-    impl ValidCapacity for ArrayString<140> {}
-    impl ValidCapacity for ArrayString<141> {}
-    impl ValidCapacity for ArrayString<142> {}
-    impl ValidCapacity for ArrayString<143> {}
-    impl ValidCapacity for ArrayString<144> {}
-    impl ValidCapacity for ArrayString<145> {}
-    impl ValidCapacity for ArrayString<146> {}
-    impl ValidCapacity for ArrayString<147> {}
-    impl ValidCapacity for ArrayString<148> {}
-    impl ValidCapacity for ArrayString<149> {}
-    impl ValidCapacity for ArrayString<150> {}
-    impl ValidCapacity for ArrayString<151> {}
-    impl ValidCapacity for ArrayString<152> {}
-    impl ValidCapacity for ArrayString<153> {}
-    impl ValidCapacity for ArrayString<154> {}
-    impl ValidCapacity for ArrayString<155> {}
-    impl ValidCapacity for ArrayString<156> {}
-    impl ValidCapacity for ArrayString<157> {}
-    impl ValidCapacity for ArrayString<158> {}
-    impl ValidCapacity for ArrayString<159> {}
-    impl ValidCapacity for ArrayString<160> {}
-    impl ValidCapacity for ArrayString<161> {}
-    impl ValidCapacity for ArrayString<162> {}
-    impl ValidCapacity for ArrayString<163> {}
-    impl ValidCapacity for ArrayString<164> {}
-    impl ValidCapacity for ArrayString<165> {}
-    impl ValidCapacity for ArrayString<166> {}
-    impl ValidCapacity for ArrayString<167> {}
-    impl ValidCapacity for ArrayString<168> {}
-    impl ValidCapacity for ArrayString<169> {}
-    impl ValidCapacity for ArrayString<170> {}
-    impl ValidCapacity for ArrayString<171> {}
-    impl ValidCapacity for ArrayString<172> {}
-    impl ValidCapacity for ArrayString<173> {}
-    impl ValidCapacity for ArrayString<174> {}
-    impl ValidCapacity for ArrayString<175> {}
-    impl ValidCapacity for ArrayString<176> {}
-    impl ValidCapacity for ArrayString<177> {}
-    impl ValidCapacity for ArrayString<178> {}
-    impl ValidCapacity for ArrayString<179> {}
-    impl ValidCapacity for ArrayString<180> {}
-    impl ValidCapacity for ArrayString<181> {}
-    impl ValidCapacity for ArrayString<182> {}
-    impl ValidCapacity for ArrayString<183> {}
-    impl ValidCapacity for ArrayString<184> {}
-    impl ValidCapacity for ArrayString<185> {}
-    impl ValidCapacity for ArrayString<186> {}
-    impl ValidCapacity for ArrayString<187> {}
-    impl ValidCapacity for ArrayString<188> {}
-    impl ValidCapacity for ArrayString<189> {}
-    impl ValidCapacity for ArrayString<190> {}
-    impl ValidCapacity for ArrayString<191> {}
-    impl ValidCapacity for ArrayString<192> {}
-    impl ValidCapacity for ArrayString<193> {}
-    impl ValidCapacity for ArrayString<194> {}
-    impl ValidCapacity for ArrayString<195> {}
-    impl ValidCapacity for ArrayString<196> {}
-    impl ValidCapacity for ArrayString<197> {}
-    impl ValidCapacity for ArrayString<198> {}
-    impl ValidCapacity for ArrayString<199> {}
-    impl ValidCapacity for ArrayString<200> {}
-    impl ValidCapacity for ArrayString<201> {}
-    impl ValidCapacity for ArrayString<202> {}
-    impl ValidCapacity for ArrayString<203> {}
-    impl ValidCapacity for ArrayString<204> {}
-    impl ValidCapacity for ArrayString<205> {}
-    impl ValidCapacity for ArrayString<206> {}
-    impl ValidCapacity for ArrayString<207> {}
-    impl ValidCapacity for ArrayString<208> {}
-    impl ValidCapacity for ArrayString<209> {}
-    impl ValidCapacity for ArrayString<210> {}
-    impl ValidCapacity for ArrayString<211> {}
-    impl ValidCapacity for ArrayString<212> {}
-    impl ValidCapacity for ArrayString<213> {}
-    impl ValidCapacity for ArrayString<214> {}
-    impl ValidCapacity for ArrayString<215> {}
-    impl ValidCapacity for ArrayString<216> {}
-    impl ValidCapacity for ArrayString<217> {}
-    impl ValidCapacity for ArrayString<218> {}
-    impl ValidCapacity for ArrayString<219> {}
-    impl ValidCapacity for ArrayString<220> {}
-    impl ValidCapacity for ArrayString<221> {}
-    impl ValidCapacity for ArrayString<222> {}
-    impl ValidCapacity for ArrayString<223> {}
-    impl ValidCapacity for ArrayString<224> {}
-    impl ValidCapacity for ArrayString<225> {}
-    impl ValidCapacity for ArrayString<226> {}
-    impl ValidCapacity for ArrayString<227> {}
-    impl ValidCapacity for ArrayString<228> {}
-    impl ValidCapacity for ArrayString<229> {}
-    impl ValidCapacity for ArrayString<230> {}
-    impl ValidCapacity for ArrayString<231> {}
-    impl ValidCapacity for ArrayString<232> {}
-    impl ValidCapacity for ArrayString<233> {}
-    impl ValidCapacity for ArrayString<234> {}
-    impl ValidCapacity for ArrayString<235> {}
-    impl ValidCapacity for ArrayString<236> {}
-    impl ValidCapacity for ArrayString<237> {}
-    impl ValidCapacity for ArrayString<238> {}
-    impl ValidCapacity for ArrayString<239> {}
-    impl ValidCapacity for ArrayString<240> {}
-    impl ValidCapacity for ArrayString<241> {}
-    impl ValidCapacity for ArrayString<242> {}
-    impl ValidCapacity for ArrayString<243> {}
-    impl ValidCapacity for ArrayString<244> {}
-    impl ValidCapacity for ArrayString<245> {}
-    impl ValidCapacity for ArrayString<246> {}
-    impl ValidCapacity for ArrayString<247> {}
-    impl ValidCapacity for ArrayString<248> {}
-    impl ValidCapacity for ArrayString<249> {}
-    impl ValidCapacity for ArrayString<250> {}
-    impl ValidCapacity for ArrayString<251> {}
-    impl ValidCapacity for ArrayString<252> {}
-    impl ValidCapacity for ArrayString<253> {}
-    impl ValidCapacity for ArrayString<254> {}
-    impl ValidCapacity for ArrayString<255> {}
+    use core::fmt::Debug;
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+
+    /// Unsigned integer used to store the length of an [`ArrayString`]
+    ///
+    /// Implemented for `u8`, `u16` and `usize`. It's sealed, so it can't be implemented outside of this crate
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Length, prelude::*};
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// fn shout<const N: usize, L: Length>(string: &mut ArrayString<N, L>) {
+    ///     string.make_ascii_uppercase();
+    /// }
+    ///
+    /// let mut string = ArrayString::<1024, u16>::from_str_truncate("hello");
+    /// shout(&mut string);
+    /// assert_eq!(string, "HELLO");
+    /// assert_eq!(<u16 as Length>::MAX, 65535);
+    /// ```
+    pub trait Length: Sealed + Copy + Debug {
+        /// Zero length, for const contexts
+        const ZERO: Self;
+        /// Biggest length representable by this type
+        const MAX: usize;
+
+        /// Converts length to `usize`
+        fn to_usize(self) -> usize;
+
+        /// Converts `usize` to length, truncating values bigger than [`MAX`]
+        ///
+        /// [`MAX`]: #associatedconstant.MAX
+        fn from_usize_lossy(size: usize) -> Self;
+    }
+
+    /// Keeps [`Length`] from being implemented outside of this crate, the module isn't reachable from outside
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for usize {}
+
+    impl Length for u8 {
+        const ZERO: Self = 0;
+        const MAX: usize = u8::MAX as usize;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_usize(self) -> usize {
+            self as usize
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn from_usize_lossy(size: usize) -> Self {
+            size as u8
+        }
+    }
+
+    impl Length for u16 {
+        const ZERO: Self = 0;
+        const MAX: usize = u16::MAX as usize;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_usize(self) -> usize {
+            self as usize
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn from_usize_lossy(size: usize) -> Self {
+            size as u16
+        }
+    }
+
+    /// Reinterprets a [`Length`] as the integer it is, since trait methods can't be called in const contexts
+    union RawLength<L: Copy> {
+        length: L,
+        u8: u8,
+        u16: u16,
+        usize: usize,
+    }
+
+    /// Converts length to `usize` in const contexts
+    #[inline]
+    pub(crate) const fn length_to_usize<L: Length>(length: L) -> usize {
+        let raw = RawLength { length };
+        // Safety: `Length` is only implemented for `u8`, `u16` and `usize`, so the size identifies the integer
+        // When `usize` has 2 bytes reading it as `u16` is still correct
+        unsafe {
+            match size_of::<L>() {
+                1 => raw.u8 as usize,
+                2 => raw.u16 as usize,
+                _ => raw.usize,
+            }
+        }
+    }
+
+    /// Converts `usize` to length in const contexts, truncating values bigger than [`Length::MAX`]
+    #[inline]
+    pub(crate) const fn usize_to_length<L: Length>(size: usize) -> L {
+        // Safety: `Length` is only implemented for `u8`, `u16` and `usize`, so the size identifies the integer
+        // When `usize` has 2 bytes writing it as `u16` is still correct
+        #[allow(clippy::cast_possible_truncation)]
+        unsafe {
+            match size_of::<L>() {
+                1 => RawLength { u8: size as u8 }.length,
+                2 => RawLength { u16: size as u16 }.length,
+                _ => RawLength { usize: size }.length,
+            }
+        }
+    }
+
+    impl Length for usize {
+        const ZERO: Self = 0;
+        const MAX: usize = usize::MAX;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_usize(self) -> usize {
+            self
+        }
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn from_usize_lossy(size: usize) -> Self {
+            size
+        }
+    }
 }
@@ -0,0 +1,600 @@
+//! ASCII-only [`ArrayString`], indexed by byte
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::utils::{is_inside_boundary, IntoLossy};
+use crate::{arraystring::sealed::Length, prelude::*, Error};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::FusedIterator;
+use core::{borrow::Borrow, cmp::Ordering, hash::Hash, hash::Hasher};
+use core::{ops::Deref, ops::Index, slice, str, str::FromStr};
+#[cfg(feature = "logs")]
+use log::{debug, trace};
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Returns error with the index of the first non-ASCII byte
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+fn is_ascii(bytes: &[u8]) -> Result<(), NotAscii> {
+    trace!("Is ascii: {bytes:?}");
+    match bytes.iter().position(|byte| !byte.is_ascii()) {
+        Some(index) => Err(NotAscii::new(index)),
+        None => Ok(()),
+    }
+}
+
+/// [`ArrayString`] that only holds ASCII, every char is a single byte
+///
+/// ASCII is validated once, on construction, so indexing, `pop`, `remove`, `insert` and reverse iteration work on bytes without looking for char boundaries. Read-only methods of [`ArrayString`] are reachable through `Deref<Target = ArrayString<N, L>>` and it converts into one for free.
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut sku = AsciiArrayString::<16>::try_from_str("BOOK-12")?;
+/// assert_eq!(sku[5], b'1');
+/// assert_eq!(sku.pop(), Some('2'));
+/// assert_eq!(sku.remove(4)?, '-');
+/// assert!(matches!(sku.try_push('é'), Err(Error::NotAscii(_))));
+/// assert_eq!(sku.chars().rev().collect::<String>(), "1KOOB");
+///
+/// let string: ArrayString<16> = sku.into();
+/// assert_eq!(string, "BOOK1");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct AsciiArrayString<const N: usize, L: Length = u8>(pub(crate) ArrayString<N, L>);
+
+impl<const N: usize, L: Length> AsciiArrayString<N, L> {
+    /// Creates new empty string.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = AsciiArrayString::<23>::new();
+    /// assert!(string.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(ArrayString::new())
+    }
+
+    /// Creates new string from string slice if it's ASCII and fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.AsciiArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = AsciiArrayString::<23>::try_from_str("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// assert!(matches!(AsciiArrayString::<23>::try_from_str("Minha Canção"), Err(Error::NotAscii(_))));
+    /// assert!(matches!(AsciiArrayString::<2>::try_from_str("BRA"), Err(Error::OutOfBounds(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, Error> {
+        is_ascii(string.as_ref().as_bytes())?;
+        Ok(Self(ArrayString::try_from_str(string)?))
+    }
+
+    /// Creates new string from byte slice if it's ASCII and fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.AsciiArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = AsciiArrayString::<23>::try_from_bytes(b"BRL")?;
+    /// assert_eq!(string.as_str(), "BRL");
+    ///
+    /// let Err(Error::NotAscii(error)) = AsciiArrayString::<23>::try_from_bytes([b'a', 0xFF]) else { unreachable!() };
+    /// assert_eq!(error.index(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let bytes = bytes.as_ref();
+        is_ascii(bytes)?;
+        is_inside_boundary(bytes.len(), Self::capacity())?;
+        // Safety: ASCII is valid utf-8 and we checked the length above
+        Ok(Self(unsafe { ArrayString::from_utf8_unchecked(bytes) }))
+    }
+
+    /// Extracts a string slice containing the entire string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = AsciiArrayString::<23>::try_from_str("My String")?;
+    /// assert_eq!(s.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Extracts a mutable byte slice containing the entire string
+    ///
+    /// # Safety
+    ///
+    /// It's UB to store non-ASCII bytes in the returned byte array
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("My String")?;
+    /// unsafe { s.as_bytes_mut()[0] = b'm' };
+    /// assert_eq!(s.as_str(), "my String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut_bytes()
+    }
+
+    /// Returns maximum string capacity, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(AsciiArrayString::<32>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// Returns the char at byte index `idx`, if any
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = AsciiArrayString::<23>::try_from_str("ABC")?;
+    /// assert_eq!(s.char_at(1), Some('B'));
+    /// assert_eq!(s.char_at(3), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn char_at(&self, idx: usize) -> Option<char> {
+        self.as_bytes().get(idx).map(|&byte| char::from(byte))
+    }
+
+    /// Returns an iterator over the chars of the string, one per byte
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = AsciiArrayString::<23>::try_from_str("ABC")?;
+    /// let mut chars = s.chars();
+    /// assert_eq!(chars.len(), 3);
+    /// assert_eq!(chars.next_back(), Some('C'));
+    /// assert_eq!(chars.next(), Some('A'));
+    /// assert_eq!(chars.as_str(), "B");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> AsciiChars<'_> {
+        AsciiChars(self.as_bytes().iter())
+    }
+
+    /// Pushes ASCII string slice to the end of the string, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<6>::try_from_str("ab")?;
+    /// s.try_push_str("cd")?;
+    /// assert!(matches!(s.try_push_str("xç"), Err(Error::NotAscii(e)) if e.index() == 1));
+    /// assert!(matches!(s.try_push_str("efg"), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "abcd");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), Error> {
+        let bytes = string.as_ref().as_bytes();
+        is_ascii(bytes)?;
+        Ok(self.0.try_push_str(string)?)
+    }
+
+    /// Pushes ASCII character to the end of the string, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<2>::try_from_str("a")?;
+    /// assert!(matches!(s.try_push('ç'), Err(Error::NotAscii(_))));
+    /// s.try_push('b')?;
+    /// assert!(matches!(s.try_push('c'), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "ab");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), Error> {
+        if !ch.is_ascii() {
+            return Err(NotAscii::new(0).into());
+        }
+        Ok(self.0.try_push(ch)?)
+    }
+
+    /// Truncates string to the specified size, does nothing if it's already smaller
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("My String")?;
+    /// s.truncate(5);
+    /// assert_eq!(s.as_str(), "My St");
+    /// s.truncate(6);
+    /// assert_eq!(s.as_str(), "My St");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate(&mut self, size: usize) {
+        debug!("Truncate: {}", size);
+        if size < self.len() {
+            self.0.size = size.into_lossy();
+        }
+    }
+
+    /// Removes last character from the string, if any.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("AB")?;
+    /// assert_eq!(s.pop(), Some('B'));
+    /// assert_eq!(s.pop(), Some('A'));
+    /// assert_eq!(s.pop(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Option<char> {
+        debug!("Pop");
+        let byte = *self.as_bytes().last()?;
+        self.0.size = (self.len() - 1).into_lossy();
+        Some(char::from(byte))
+    }
+
+    /// Removes the character at byte index `idx`, returns error if it's out of bounds
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("ABCD")?;
+    /// assert!(s.remove(4).is_err());
+    /// assert_eq!(s.remove(1)?, 'B');
+    /// assert_eq!(s.as_str(), "ACD");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn remove(&mut self, idx: usize) -> Result<char, OutOfBounds> {
+        debug!("Remove: {}", idx);
+        let len = self.len();
+        is_inside_boundary(idx.saturating_add(1), len)?;
+        // Safety: `idx < len` was checked above and `len` is never bigger than capacity
+        unsafe {
+            let ptr = self.0.array.as_mut_ptr().add(idx);
+            let byte = *ptr;
+            core::ptr::copy(ptr.add(1), ptr, len - idx - 1);
+            self.0.size = (len - 1).into_lossy();
+            Ok(char::from(byte))
+        }
+    }
+
+    /// Inserts ASCII character at byte index `idx`, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<4>::try_from_str("AC")?;
+    /// s.try_insert(1, 'B')?;
+    /// assert_eq!(s.as_str(), "ABC");
+    /// assert!(matches!(s.try_insert(0, 'Á'), Err(Error::NotAscii(_))));
+    /// assert!(matches!(s.try_insert(4, 'D'), Err(Error::OutOfBounds(_))));
+    /// s.try_insert(3, 'D')?;
+    /// assert!(matches!(s.try_insert(0, '0'), Err(Error::OutOfBounds(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), Error> {
+        debug!("Insert {ch} at {idx}");
+        if !ch.is_ascii() {
+            return Err(NotAscii::new(0).into());
+        }
+        let len = self.len();
+        is_inside_boundary(idx, len)?;
+        is_inside_boundary(len + 1, Self::capacity())?;
+        // Safety: `idx <= len < capacity` was checked above
+        unsafe {
+            let ptr = self.0.array.as_mut_ptr().add(idx);
+            core::ptr::copy(ptr, ptr.add(1), len - idx);
+            *ptr = ch as u8;
+        }
+        self.0.size = (len + 1).into_lossy();
+        Ok(())
+    }
+
+    /// Empties the string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("ABCD")?;
+    /// s.clear();
+    /// assert!(s.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    /// Converts the string to its ASCII upper case equivalent in-place
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("br")?;
+    /// s.make_ascii_uppercase();
+    /// assert_eq!(s.as_str(), "BR");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.make_ascii_uppercase()
+    }
+
+    /// Converts the string to its ASCII lower case equivalent in-place
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = AsciiArrayString::<23>::try_from_str("BR")?;
+    /// s.make_ascii_lowercase();
+    /// assert_eq!(s.as_str(), "br");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.make_ascii_lowercase()
+    }
+}
+
+impl<const N: usize, L: Length> Deref for AsciiArrayString<N, L> {
+    type Target = ArrayString<N, L>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize, L: Length> AsRef<str> for AsciiArrayString<N, L> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, L: Length> AsRef<[u8]> for AsciiArrayString<N, L> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize, L: Length> Borrow<str> for AsciiArrayString<N, L> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, L: Length> Index<usize> for AsciiArrayString<N, L> {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &u8 {
+        &self.as_bytes()[idx]
+    }
+}
+
+impl<const N: usize, L: Length> From<AsciiArrayString<N, L>> for ArrayString<N, L> {
+    #[inline]
+    fn from(string: AsciiArrayString<N, L>) -> Self {
+        string.0
+    }
+}
+
+impl<const N: usize, L: Length> TryFrom<ArrayString<N, L>> for AsciiArrayString<N, L> {
+    type Error = NotAscii;
+
+    #[inline]
+    fn try_from(string: ArrayString<N, L>) -> Result<Self, Self::Error> {
+        is_ascii(string.as_bytes())?;
+        Ok(Self(string))
+    }
+}
+
+impl<'a, const N: usize, L: Length> TryFrom<&'a str> for AsciiArrayString<N, L> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, L: Length> FromStr for AsciiArrayString<N, L> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, L: Length> Debug for AsciiArrayString<N, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("AsciiArrayString").field(&self.0).finish()
+    }
+}
+
+impl<const N: usize, L: Length> Display for AsciiArrayString<N, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize, L: Length> PartialEq for AsciiArrayString<N, L> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const N: usize, L: Length> Eq for AsciiArrayString<N, L> {}
+
+impl<const N: usize, L: Length> PartialEq<str> for AsciiArrayString<N, L> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl<const N: usize, L: Length> PartialEq<&str> for AsciiArrayString<N, L> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl<const N: usize, L: Length> PartialEq<ArrayString<N, L>> for AsciiArrayString<N, L> {
+    #[inline]
+    fn eq(&self, other: &ArrayString<N, L>) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const N: usize, L: Length> Ord for AsciiArrayString<N, L> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize, L: Length> PartialOrd for AsciiArrayString<N, L> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, L: Length> Hash for AsciiArrayString<N, L> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+/// Iterator over the chars of an [`AsciiArrayString`], one per byte
+///
+/// Created through [`chars`]
+///
+/// [`AsciiArrayString`]: ./struct.AsciiArrayString.html
+/// [`chars`]: ./struct.AsciiArrayString.html#method.chars
+#[derive(Clone)]
+pub struct AsciiChars<'a>(slice::Iter<'a, u8>);
+
+impl<'a> AsciiChars<'a> {
+    /// Extracts string slice containing the remaining characters
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        // Safety: the bytes come from an `AsciiArrayString`, ASCII is valid utf-8
+        unsafe { str::from_utf8_unchecked(self.0.as_slice()) }
+    }
+}
+
+impl Debug for AsciiChars<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("AsciiChars").field(&self.as_str()).finish()
+    }
+}
+
+impl Iterator for AsciiChars<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|&byte| char::from(byte))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for AsciiChars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|&byte| char::from(byte))
+    }
+}
+
+impl ExactSizeIterator for AsciiChars<'_> {}
+
+impl FusedIterator for AsciiChars<'_> {}
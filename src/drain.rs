@@ -2,7 +2,7 @@
 //!
 //! [`ArrayString`]: ./struct.ArrayString.html
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+use crate::{arraystring::sealed::Length, prelude::*};
 use core::fmt::{self, Debug, Formatter};
 use core::{cmp::Ordering, hash::Hash, hash::Hasher, iter::FusedIterator};
 
@@ -12,83 +12,57 @@ use core::{cmp::Ordering, hash::Hash, hash::Hasher, iter::FusedIterator};
 ///
 /// [`ArrayString`]: ./struct.ArrayString.html
 /// [`drain`]: ./struct.ArrayString.html#method.drain
-pub struct Drain<const N: usize>(pub(crate) ArrayString<N>)
-where
-    ArrayString<N>: ValidCapacity;
+pub struct Drain<const N: usize, L: Length = u8>(pub(crate) ArrayString<N, L>);
 
-impl<const N: usize> Copy for Drain<N> where ArrayString<N>: ValidCapacity {}
-impl<const N: usize> Clone for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Copy for Drain<N, L> {}
+impl<const N: usize, L: Length> Clone for Drain<N, L> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        *self
     }
 }
-impl<const N: usize> Default for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Default for Drain<N, L> {
     fn default() -> Self {
         Self(Default::default())
     }
 }
 
-impl<const N: usize> Debug for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Debug for Drain<N, L> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.debug_tuple("Drain").field(&self.0).finish()
     }
 }
 
-impl<const N: usize> PartialEq for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> PartialEq for Drain<N, L> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.as_str().eq(other.as_str())
     }
 }
-impl<const N: usize> Eq for Drain<N> where ArrayString<N>: ValidCapacity {}
+impl<const N: usize, L: Length> Eq for Drain<N, L> {}
 
-impl<const N: usize> Ord for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Ord for Drain<N, L> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.as_str().cmp(other.as_str())
     }
 }
 
-impl<const N: usize> PartialOrd for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> PartialOrd for Drain<N, L> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<const N: usize> Hash for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Hash for Drain<N, L> {
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
         self.as_str().hash(hasher)
     }
 }
 
-impl<const N: usize> Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Drain<N, L> {
     /// Extracts string slice containing the remaining characters of `Drain`.
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -96,10 +70,7 @@ where
     }
 }
 
-impl<const N: usize> Iterator for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> Iterator for Drain<N, L> {
     type Item = char;
 
     #[inline]
@@ -108,14 +79,11 @@ where
     }
 }
 
-impl<const N: usize> DoubleEndedIterator for Drain<N>
-where
-    ArrayString<N>: ValidCapacity,
-{
+impl<const N: usize, L: Length> DoubleEndedIterator for Drain<N, L> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.0.pop()
     }
 }
 
-impl<const N: usize> FusedIterator for Drain<N> where ArrayString<N>: ValidCapacity {}
+impl<const N: usize, L: Length> FusedIterator for Drain<N, L> {}
@@ -1,199 +1,569 @@
 //! Contains all of this crate's errors
 
 use core::fmt::{self, Debug, Display, Formatter};
-use core::{char::DecodeUtf16Error, hash::Hash, hash::Hasher, str::EncodeUtf16, str::Utf8Error};
+use core::{char::DecodeUtf16Error, str::Utf8Error};
 #[cfg(feature = "logs")]
 use log::trace;
 
 /// Every error possible when using [`ArrayString`]
 ///
 /// [`ArrayString`]: ../struct.ArrayString.html
-#[derive(Copy, Clone)]
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let error = ArrayString::<23>::try_from_utf8([b'a', 0xFF]).unwrap_err();
+/// assert!(matches!(error, Error::Utf8(_)));
+/// assert_eq!(error.to_string(), "invalid utf-8 sequence of 1 bytes from index 1");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Error {
-    /// Invalid UTF-8 access (invalid data or invalid utf-8 character index)
-    Utf8,
+    /// Invalid UTF-8 data
+    Utf8(Utf8),
     /// Failed to convert `[u16]` to UTF-16 string
-    Utf16,
+    Utf16(Utf16),
     /// Out of bounds access
-    OutOfBounds,
+    OutOfBounds(OutOfBounds),
+    /// Index is not at a utf-8 char boundary
+    NotCharBoundary(NotCharBoundary),
+    /// Range starts after it ends
+    InvalidRange(InvalidRange),
+    /// String doesn't uphold a [`Validator`]'s invariant
+    ///
+    /// [`Validator`]: ../validator/trait.Validator.html
+    Invalid(Invalid),
+    /// Non-ASCII data where only ASCII is allowed
+    NotAscii(NotAscii),
 }
 
-impl PartialEq for Error {
+impl Display for Error {
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        use self::Error::*;
-        matches!(
-            (self, other),
-            (Utf8, Utf8) | (Utf16, Utf16) | (OutOfBounds, OutOfBounds)
-        )
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::Utf8(error) => Display::fmt(error, f),
+            Error::Utf16(error) => Display::fmt(error, f),
+            Error::OutOfBounds(error) => Display::fmt(error, f),
+            Error::NotCharBoundary(error) => Display::fmt(error, f),
+            Error::InvalidRange(error) => Display::fmt(error, f),
+            Error::Invalid(error) => Display::fmt(error, f),
+            Error::NotAscii(error) => Display::fmt(error, f),
+        }
     }
 }
 
-impl Eq for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
 
-impl Hash for Error {
+impl From<Utf8Error> for Error {
     #[inline]
-    fn hash<H: Hasher>(&self, hasher: &mut H) {
-        match self {
-            Error::Utf8 => "Utf8".hash(hasher),
-            Error::Utf16 => "Utf16".hash(hasher),
-            Error::OutOfBounds => "OutOfBounds".hash(hasher),
-        }
+    fn from(error: Utf8Error) -> Self {
+        Error::Utf8(error.into())
     }
 }
 
-impl Display for Error {
+impl From<DecodeUtf16Error> for Error {
     #[inline]
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            Error::Utf8 => write!(f, "Utf8"),
-            Error::Utf16 => write!(f, "Utf16"),
-            Error::OutOfBounds => write!(f, "OutOfBounds"),
+    fn from(error: DecodeUtf16Error) -> Self {
+        Error::Utf16(error.into())
+    }
+}
+
+/// Error caused by invalid UTF-8 data, like `core::str::Utf8Error` it tells where the valid prefix ends
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let error = ArrayString::<23>::from_utf8_truncate([b'a', b'b', 0xF0, 0x9F]).unwrap_err();
+/// assert_eq!(error.valid_up_to(), 2);
+/// assert_eq!(error.error_len(), None);
+/// assert_eq!(error.to_string(), "incomplete utf-8 byte sequence from index 2");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8 {
+    /// Index up to which the data is valid utf-8
+    valid_up_to: usize,
+    /// Length of the invalid sequence, `None` if the data ended in the middle of a sequence
+    error_len: Option<u8>,
+}
+
+impl Utf8 {
+    /// Creates error for invalid data starting at `valid_up_to`
+    #[inline]
+    pub(crate) const fn new(valid_up_to: usize, error_len: Option<u8>) -> Self {
+        Self {
+            valid_up_to,
+            error_len,
+        }
+    }
+
+    /// Moves the error to `valid_up_to`, for data validated in pieces
+    #[inline]
+    pub(crate) const fn with_valid_up_to(self, valid_up_to: usize) -> Self {
+        Self::new(valid_up_to, self.error_len)
+    }
+
+    /// Index up to which the data is valid utf-8
+    #[inline]
+    pub const fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Length of the invalid byte sequence, `None` if the data ended in the middle of a char
+    #[inline]
+    pub const fn error_len(&self) -> Option<usize> {
+        match self.error_len {
+            Some(len) => Some(len as usize),
+            None => None,
         }
     }
 }
 
-impl Debug for Error {
+impl Display for Utf8 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            Error::Utf8 => write!(f, "Error::Utf8"),
-            Error::Utf16 => write!(f, "Error::Utf16"),
-            Error::OutOfBounds => write!(f, "Error::OutOfBounds"),
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid utf-8 sequence of {len} bytes from index {}",
+                self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                self.valid_up_to
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Utf8 {}
 
-impl From<Utf8Error> for Error {
+impl From<Utf8Error> for Utf8 {
     #[inline]
-    fn from(_: Utf8Error) -> Self {
-        Error::Utf8
+    fn from(error: Utf8Error) -> Self {
+        // A utf-8 error sequence has at most 3 bytes
+        Self::new(error.valid_up_to(), error.error_len().map(|len| len as u8))
     }
 }
 
-impl From<DecodeUtf16Error> for Error {
+impl From<Utf8> for Error {
     #[inline]
-    fn from(_: DecodeUtf16Error) -> Self {
-        Error::Utf16
+    fn from(error: Utf8) -> Self {
+        trace!("From Utf8");
+        Error::Utf8(error)
     }
 }
 
-impl<'a> From<EncodeUtf16<'a>> for Error {
+/// Error caused by invalid UTF-16 data, it carries the unpaired surrogate found
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let error = ArrayString::<23>::from_utf16_truncate([0x0061, 0xD800]).unwrap_err();
+/// assert_eq!(error.unpaired_surrogate(), 0xD800);
+/// assert_eq!(error.to_string(), "unpaired surrogate found: d800");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf16 {
+    /// Surrogate without its pair
+    unpaired_surrogate: u16,
+}
+
+impl Utf16 {
+    /// Unpaired surrogate that caused the error
     #[inline]
-    fn from(_: EncodeUtf16) -> Self {
-        Error::Utf16
+    pub const fn unpaired_surrogate(&self) -> u16 {
+        self.unpaired_surrogate
     }
 }
 
-/// Error caused by invalid UTF-8 data
-#[derive(Copy, Clone, Default, PartialEq, Eq)]
-pub struct Utf8;
-
-impl Debug for Utf8 {
+impl Display for Utf16 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf8")
+        write!(f, "unpaired surrogate found: {:x}", self.unpaired_surrogate)
     }
 }
 
-impl Display for Utf8 {
+#[cfg(feature = "std")]
+impl std::error::Error for Utf16 {}
+
+impl From<Utf16> for Error {
+    #[inline]
+    fn from(error: Utf16) -> Self {
+        trace!("From Utf16");
+        Error::Utf16(error)
+    }
+}
+
+impl From<DecodeUtf16Error> for Utf16 {
+    #[inline]
+    fn from(error: DecodeUtf16Error) -> Self {
+        Self {
+            unpaired_surrogate: error.unpaired_surrogate(),
+        }
+    }
+}
+
+/// Error caused by out of bounds access to [`ArrayString`], it carries the length required and the capacity available
+///
+/// For index access the capacity is the current length of the string.
+///
+/// [`ArrayString`]: ../struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let error = ArrayString::<5>::try_from_str("ABCD🤔").unwrap_err();
+/// assert_eq!(error.required(), 8);
+/// assert_eq!(error.capacity(), 5);
+/// assert_eq!(error.to_string(), "out of bounds: required 8 bytes, but capacity is 5");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfBounds {
+    /// Length required by the operation
+    required: usize,
+    /// Length available
+    capacity: usize,
+}
+
+impl OutOfBounds {
+    /// Creates error for an operation that requires `required` bytes, but only has `capacity`
+    #[inline]
+    pub(crate) const fn new(required: usize, capacity: usize) -> Self {
+        Self { required, capacity }
+    }
+
+    /// Length required by the operation, saturates at `usize::MAX`
+    #[inline]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Length available, the string's capacity (or its length for index access)
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Display for OutOfBounds {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf8")
+        write!(
+            f,
+            "out of bounds: required {} bytes, but capacity is {}",
+            self.required, self.capacity
+        )
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Utf8 {}
+impl std::error::Error for OutOfBounds {}
 
-impl From<Utf8Error> for Utf8 {
+impl From<OutOfBounds> for Error {
     #[inline]
-    fn from(_: Utf8Error) -> Self {
-        Utf8
+    fn from(error: OutOfBounds) -> Self {
+        trace!("From OutOfBounds");
+        Error::OutOfBounds(error)
     }
 }
 
-impl From<Utf8> for Error {
+/// Error caused by an index in the middle of a utf-8 char, a bug in the caller rather than bad data
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<23>::from_str_truncate("A🤔");
+/// let error = s.truncate(2).unwrap_err();
+/// assert_eq!(error.index(), 2);
+/// assert_eq!(error.to_string(), "index 2 is not on a char boundary");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotCharBoundary {
+    /// Index inside of a char
+    index: usize,
+}
+
+impl NotCharBoundary {
+    /// Creates error for `index` in the middle of a char
     #[inline]
-    fn from(_: Utf8) -> Self {
-        trace!("From Utf8");
-        Error::Utf8
+    pub(crate) const fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Index that is in the middle of a char
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Display for NotCharBoundary {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "index {} is not on a char boundary", self.index)
     }
 }
 
-/// Error caused by invalid UTF-16 data
-#[derive(Copy, Clone, Default, PartialEq, Eq)]
-pub struct Utf16;
+#[cfg(feature = "std")]
+impl std::error::Error for NotCharBoundary {}
+
+impl From<NotCharBoundary> for Error {
+    #[inline]
+    fn from(error: NotCharBoundary) -> Self {
+        trace!("From NotCharBoundary");
+        Error::NotCharBoundary(error)
+    }
+}
+
+/// Error caused by a range that starts after it ends
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<23>::from_str_truncate("ABCD");
+/// let Err(Error::InvalidRange(error)) = s.replace_range(3..1, "") else { unreachable!() };
+/// assert_eq!((error.start(), error.end()), (3, 1));
+/// assert_eq!(error.to_string(), "invalid range: start 3 is greater than end 1");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidRange {
+    /// Start of the range
+    start: usize,
+    /// End of the range, smaller than the start
+    end: usize,
+}
+
+impl InvalidRange {
+    /// Creates error for a range with `start > end`
+    #[inline]
+    pub(crate) const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Start of the range
+    #[inline]
+    pub const fn start(&self) -> usize {
+        self.start
+    }
 
-impl Debug for Utf16 {
+    /// End of the range, smaller than the start
+    #[inline]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+}
+
+impl Display for InvalidRange {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf16")
+        write!(
+            f,
+            "invalid range: start {} is greater than end {}",
+            self.start, self.end
+        )
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Utf16 {}
+impl std::error::Error for InvalidRange {}
 
-impl Display for Utf16 {
+impl From<InvalidRange> for Error {
+    #[inline]
+    fn from(error: InvalidRange) -> Self {
+        trace!("From InvalidRange");
+        Error::InvalidRange(error)
+    }
+}
+
+/// Error caused by a string that doesn't uphold a [`Validator`]'s invariant
+///
+/// [`Validator`]: ../validator/trait.Validator.html
+///
+/// ```rust
+/// # use arraystring::{Error, validator::Ascii, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let error = ValidatedArrayString::<23, Ascii>::try_from_str("Olá").unwrap_err();
+/// assert!(matches!(error, Error::Invalid(_)));
+/// assert_eq!(error.to_string(), "invalid string: not ascii");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Invalid {
+    /// Which invariant was broken
+    reason: &'static str,
+}
+
+impl Invalid {
+    /// Creates error explaining which invariant was broken
+    #[inline]
+    pub const fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+
+    /// Which invariant was broken
+    #[inline]
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl Display for Invalid {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf16")
+        write!(f, "invalid string: {}", self.reason)
     }
 }
 
-impl From<Utf16> for Error {
+#[cfg(feature = "std")]
+impl std::error::Error for Invalid {}
+
+impl From<Invalid> for Error {
     #[inline]
-    fn from(_: Utf16) -> Self {
-        trace!("From Utf16");
-        Error::Utf16
+    fn from(error: Invalid) -> Self {
+        trace!("From Invalid");
+        Error::Invalid(error)
     }
 }
 
-impl From<DecodeUtf16Error> for Utf16 {
+/// Error caused by non-ASCII data in an [`AsciiArrayString`], it tells where the ASCII prefix ends
+///
+/// Like [`Utf8`], the index points into the data that was rejected, not into the string it would be added to
+///
+/// [`AsciiArrayString`]: ../struct.AsciiArrayString.html
+/// [`Utf8`]: ./struct.Utf8.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let string = ArrayString::<23>::from_str_truncate("Olá");
+/// let error = AsciiArrayString::try_from(string).unwrap_err();
+/// assert_eq!(error.index(), 2);
+/// assert_eq!(error.to_string(), "non-ascii byte found at index 2");
+///
+/// let mut string = AsciiArrayString::<23>::try_from_str("Hello")?;
+/// assert!(matches!(string.try_push_str(", Olá"), Err(Error::NotAscii(e)) if e.index() == 4));
+/// assert!(matches!(string.try_push('á'), Err(Error::NotAscii(e)) if e.index() == 0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotAscii {
+    /// Index of the first non-ASCII byte
+    index: usize,
+}
+
+impl NotAscii {
+    /// Creates error for a non-ASCII byte at `index`
     #[inline]
-    fn from(_: DecodeUtf16Error) -> Self {
-        Utf16
+    pub(crate) const fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Index of the first non-ASCII byte, in the data rejected (the argument, for a char it's always 0)
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
     }
 }
 
-impl<'a> From<EncodeUtf16<'a>> for Utf16 {
+impl Display for NotAscii {
     #[inline]
-    fn from(_: EncodeUtf16) -> Self {
-        Utf16
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "non-ascii byte found at index {}", self.index)
     }
 }
 
-/// Error caused by out of bounds access to [`ArrayString`]
+#[cfg(feature = "std")]
+impl std::error::Error for NotAscii {}
+
+impl From<NotAscii> for Error {
+    #[inline]
+    fn from(error: NotAscii) -> Self {
+        trace!("From NotAscii");
+        Error::NotAscii(error)
+    }
+}
+
+/// Error caused by an owned string that doesn't fit in [`ArrayString`], it gives the original value back so no data is lost
 ///
 /// [`ArrayString`]: ../struct.ArrayString.html
-#[derive(Copy, Clone, Default, PartialEq, Eq)]
-pub struct OutOfBounds;
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let string = "0".repeat(30);
+/// let error = ArrayString::<23>::try_from(string).unwrap_err();
+/// assert_eq!(error.error().required(), 30);
+/// assert_eq!(error.into_inner(), "0".repeat(30));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct OutOfBoundsWith<T> {
+    /// Why the value didn't fit
+    error: OutOfBounds,
+    /// Value that didn't fit
+    value: T,
+}
+
+impl<T> OutOfBoundsWith<T> {
+    /// Wraps the value that didn't fit
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn new(error: OutOfBounds, value: T) -> Self {
+        Self { error, value }
+    }
+
+    /// Returns why the value didn't fit
+    #[inline]
+    pub fn error(&self) -> OutOfBounds {
+        self.error
+    }
+
+    /// Returns the value that didn't fit
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Borrows the value that didn't fit
+    #[inline]
+    pub fn as_inner(&self) -> &T {
+        &self.value
+    }
+}
 
-impl Debug for OutOfBounds {
+impl<T> Debug for OutOfBoundsWith<T> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "OutOfBounds")
+        f.debug_struct("OutOfBoundsWith")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
     }
 }
 
-impl Display for OutOfBounds {
+impl<T> Display for OutOfBoundsWith<T> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "OutOfBounds")
+        Display::fmt(&self.error, f)
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for OutOfBounds {}
+impl<T> std::error::Error for OutOfBoundsWith<T> {}
 
-impl From<OutOfBounds> for Error {
+impl<T> From<OutOfBoundsWith<T>> for OutOfBounds {
     #[inline]
-    fn from(_: OutOfBounds) -> Self {
-        trace!("From OutOfBounds");
-        Error::OutOfBounds
+    fn from(error: OutOfBoundsWith<T>) -> Self {
+        trace!("From OutOfBoundsWith");
+        error.error
+    }
+}
+
+impl<T> From<OutOfBoundsWith<T>> for Error {
+    #[inline]
+    fn from(error: OutOfBoundsWith<T>) -> Self {
+        trace!("From OutOfBoundsWith");
+        Error::OutOfBounds(error.error)
     }
 }
@@ -1,18 +1,23 @@
 //! Trait implementations for `ArrayString` (that aren't for integration)
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+#[cfg(feature = "std")]
+use crate::utils::utf8_char_width;
+use crate::{arraystring::sealed::Length, prelude::*};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc};
 use core::fmt::{self, Debug, Display, Formatter, Write};
 use core::ops::{Add, Deref, DerefMut, Index, IndexMut};
 use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use core::str::{self, FromStr};
 use core::{borrow::Borrow, borrow::BorrowMut, cmp::Ordering, hash::Hash, hash::Hasher};
+#[cfg(all(feature = "logs", feature = "std"))]
+use log::trace;
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
 use no_panic::no_panic;
+#[cfg(feature = "std")]
+use std::io;
 
-impl<const N: usize> Default for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Default for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn default() -> Self {
@@ -20,25 +25,15 @@ where
     }
 }
 
-impl<const N: usize> AsRef<str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> AsRef<str> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn as_ref(&self) -> &str {
-        // Safety: our byte slice should only contain valid utf-8
-        // There is no way to invalidate the utf-8 of it from safe functions
-        // And it's a invariant expected to be kept in unsafe functions
-        debug_assert!(str::from_utf8(self.as_ref()).is_ok());
-        unsafe { str::from_utf8_unchecked(self.as_ref()) }
+        self.as_str()
     }
 }
 
-impl<const N: usize> AsMut<str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> AsMut<str> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn as_mut(&mut self) -> &mut str {
@@ -54,10 +49,7 @@ where
     }
 }
 
-impl<const N: usize> AsRef<[u8]> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> AsRef<[u8]> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn as_ref(&self) -> &[u8] {
@@ -67,10 +59,7 @@ where
     }
 }
 
-impl<'a, const N: usize> From<&'a str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> From<&str> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from(s: &str) -> Self {
@@ -78,10 +67,104 @@ where
     }
 }
 
-impl<const N: usize> FromStr for ArrayString<N>
-where
-    Self: ValidCapacity,
+/// Copies the content to an `ArrayString` of another capacity, returning [`OutOfBounds`] if it doesn't fit
+///
+/// It's implemented for references since a by value impl would overlap with `From<T> for T` when both capacities are the same. `ArrayString` is `Copy`, so this doesn't restrict anything.
+///
+/// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let s = MaxString::try_from_str("My String")?;
+/// let small = ArrayString::<20>::try_from(&s)?;
+/// assert_eq!(small.as_str(), "My String");
+///
+/// assert!(ArrayString::<5>::try_from(&s).is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl<const N: usize, const M: usize, L: Length, L2: Length> TryFrom<&ArrayString<N, L>>
+    for ArrayString<M, L2>
 {
+    type Error = OutOfBounds;
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_from(string: &ArrayString<N, L>) -> Result<Self, Self::Error> {
+        Self::try_from_str(string)
+    }
+}
+
+/// Implements `TryFrom` for owned strings, giving them back in [`OutOfBoundsWith`] if they don't fit
+///
+/// [`OutOfBoundsWith`]: ./error/struct.OutOfBoundsWith.html
+macro_rules! impl_try_from_owned {
+    ($([$($lt:lifetime)?] $owned:ty;)*) => {$(
+        #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+        #[cfg(feature = "alloc")]
+        impl<$($lt,)? const N: usize, L: Length> TryFrom<$owned> for ArrayString<N, L> {
+            type Error = OutOfBoundsWith<$owned>;
+
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn try_from(string: $owned) -> Result<Self, Self::Error> {
+                Self::try_from_str(&string[..]).map_err(|err| OutOfBoundsWith::new(err, string))
+            }
+        }
+    )*};
+}
+
+impl_try_from_owned! {
+    [] String;
+    [] Box<str>;
+    ['a] Cow<'a, str>;
+}
+
+/// Implements `From<ArrayString>` for heap allocated strings
+macro_rules! impl_into_allocated {
+    ($($allocated:ty),*) => {$(
+        #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+        #[cfg(feature = "alloc")]
+        impl<const N: usize, L: Length> From<ArrayString<N, L>> for $allocated {
+            #[inline]
+            fn from(string: ArrayString<N, L>) -> Self {
+                Self::from(string.as_str())
+            }
+        }
+    )*};
+}
+
+impl_into_allocated!(String, Box<str>, Rc<str>, Arc<str>);
+
+/// Borrows the content, so `Cow::into_owned` and `ArrayString::try_from(cow)` round-trip without copying twice
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # use std::borrow::Cow;
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let s = ArrayString::<23>::try_from_str("My String")?;
+/// let cow = Cow::from(&s);
+/// assert_eq!(ArrayString::<23>::try_from(cow.clone())?, s);
+///
+/// let owned: String = cow.into_owned();
+/// assert_eq!(ArrayString::<23>::try_from(owned)?, s);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize, L: Length> From<&'a ArrayString<N, L>> for Cow<'a, str> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn from(string: &'a ArrayString<N, L>) -> Self {
+        Cow::Borrowed(string.as_str())
+    }
+}
+
+impl<const N: usize, L: Length> FromStr for ArrayString<N, L> {
     type Err = OutOfBounds;
 
     #[inline]
@@ -91,10 +174,7 @@ where
     }
 }
 
-impl<const N: usize> Debug for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Debug for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -105,32 +185,59 @@ where
     }
 }
 
-impl<const N: usize> PartialEq<str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &str) -> bool {
-        self.as_str().eq(other)
-    }
-}
-
-impl<const N: usize> PartialEq<&str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &&str) -> bool {
-        self.eq(*other)
-    }
-}
-
-impl<const N: usize> Borrow<str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+/// Implements symmetric `PartialEq` and `PartialOrd` between `ArrayString` and string types that index to `str`
+macro_rules! impl_str_comparisons {
+    ($($(#[$attr:meta])* [$($lt:lifetime)?] $other:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($lt,)? const N: usize, L: Length> PartialEq<$other> for ArrayString<N, L> {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn eq(&self, other: &$other) -> bool {
+                self.as_str().eq(&other[..])
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($lt,)? const N: usize, L: Length> PartialEq<ArrayString<N, L>> for $other {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn eq(&self, other: &ArrayString<N, L>) -> bool {
+                self[..].eq(other.as_str())
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($lt,)? const N: usize, L: Length> PartialOrd<$other> for ArrayString<N, L> {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                self.as_str().partial_cmp(&other[..])
+            }
+        }
+
+        $(#[$attr])*
+        impl<$($lt,)? const N: usize, L: Length> PartialOrd<ArrayString<N, L>> for $other {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn partial_cmp(&self, other: &ArrayString<N, L>) -> Option<Ordering> {
+                self[..].partial_cmp(other.as_str())
+            }
+        }
+    )*};
+}
+
+impl_str_comparisons! {
+    [] str;
+    ['a] &'a str;
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    [] String;
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    ['a] Cow<'a, str>;
+}
+
+impl<const N: usize, L: Length> Borrow<str> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn borrow(&self) -> &str {
@@ -138,10 +245,7 @@ where
     }
 }
 
-impl<const N: usize> BorrowMut<str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> BorrowMut<str> for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn borrow_mut(&mut self) -> &mut str {
@@ -149,10 +253,7 @@ where
     }
 }
 
-impl<const N: usize> Hash for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Hash for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
@@ -160,23 +261,19 @@ where
     }
 }
 
-impl<const N: usize> PartialEq for ArrayString<N>
-where
-    Self: ValidCapacity,
+impl<const N: usize, const M: usize, L: Length, L2: Length> PartialEq<ArrayString<M, L2>>
+    for ArrayString<N, L>
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &ArrayString<M, L2>) -> bool {
         self.as_str().eq(other.as_str())
     }
 }
 
-impl<const N: usize> Eq for ArrayString<N> where Self: ValidCapacity {}
+impl<const N: usize, L: Length> Eq for ArrayString<N, L> {}
 
-impl<const N: usize> Ord for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Ord for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -184,21 +281,17 @@ where
     }
 }
 
-impl<const N: usize> PartialOrd for ArrayString<N>
-where
-    Self: ValidCapacity,
+impl<const N: usize, const M: usize, L: Length, L2: Length> PartialOrd<ArrayString<M, L2>>
+    for ArrayString<N, L>
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn partial_cmp(&self, other: &ArrayString<M, L2>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
     }
 }
 
-impl<'a, const N: usize> Add<&'a str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Add<&str> for ArrayString<N, L> {
     type Output = Self;
 
     #[inline]
@@ -209,10 +302,7 @@ where
     }
 }
 
-impl<const N: usize> Write for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Write for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn write_str(&mut self, slice: &str) -> fmt::Result {
@@ -220,10 +310,91 @@ where
     }
 }
 
-impl<const N: usize> Display for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl<const N: usize, L: Length> io::Write for ArrayString<N, L> {
+    /// Appends utf-8 bytes to the string, returning `ErrorKind::WriteZero` if nothing fits and `ErrorKind::InvalidData` on invalid utf-8
+    ///
+    /// Writes are only cut at char boundaries. A char split between writes is invalid here, since the string has nowhere to keep its start, use [`writer`] for that.
+    ///
+    /// [`writer`]: ./struct.ArrayString.html#method.writer
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use std::io::{self, Write};
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::new();
+    /// s.write_all("🤔".as_bytes())?;
+    /// assert_eq!(s.as_str(), "🤔");
+    ///
+    /// assert_eq!(s.write(b"abcde")?, 4);
+    /// assert_eq!(s.write(b"f").unwrap_err().kind(), io::ErrorKind::WriteZero);
+    /// assert_eq!(s.as_str(), "🤔abcd");
+    ///
+    /// s.clear();
+    /// assert_eq!(s.write(b"\xFF").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    /// assert_eq!(s.write(b"a\xF0\x9F")?, 1);
+    /// assert_eq!(s.write(b"\xF0\x9F").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        trace!("Io write: {buf:?} to {self}");
+        match write_utf8(self, buf)? {
+            (0, incomplete) if !incomplete.is_empty() => Err(io::ErrorKind::InvalidData.into()),
+            (written, _) => Ok(written),
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Appends the valid utf-8 prefix of `buf` that fits, returns how many bytes were written and the incomplete char cut off at the end of `buf`
+///
+/// The incomplete char is only returned if everything before it was written and the whole char would fit too, it's empty otherwise
+#[cfg(feature = "std")]
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn write_utf8<'a, const N: usize, L: Length>(
+    string: &mut ArrayString<N, L>,
+    buf: &'a [u8],
+) -> io::Result<(usize, &'a [u8])> {
+    let (valid, incomplete) = match str::from_utf8(buf) {
+        Ok(valid) => (valid, Default::default()),
+        Err(err) => {
+            let (valid, invalid) = buf.split_at(err.valid_up_to());
+            // Safety: `valid_up_to` bytes were validated as utf-8 above
+            let valid = unsafe { str::from_utf8_unchecked(valid) };
+            match err.error_len() {
+                None => (valid, invalid),
+                // Invalid data is reported once the valid prefix has been consumed
+                Some(_) if !valid.is_empty() => (valid, Default::default()),
+                Some(_) => return Err(io::ErrorKind::InvalidData.into()),
+            }
+        }
+    };
+    let len = string.len();
+    string.push_str_truncate(valid);
+    let written = string.len() - len;
+    let width = incomplete.first().copied().map_or(0, utf8_char_width);
+    let incomplete = match written == valid.len() && string.len() + width <= N {
+        true => incomplete,
+        false => Default::default(),
+    };
+    if written == 0 && incomplete.is_empty() && !buf.is_empty() {
+        return Err(io::ErrorKind::WriteZero.into());
+    }
+    Ok((written, incomplete))
+}
+
+impl<const N: usize, L: Length> Display for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -231,10 +402,7 @@ where
     }
 }
 
-impl<const N: usize> Deref for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Deref for ArrayString<N, L> {
     type Target = str;
 
     #[inline]
@@ -244,10 +412,7 @@ where
     }
 }
 
-impl<const N: usize> DerefMut for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> DerefMut for ArrayString<N, L> {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn deref_mut(&mut self) -> &mut Self::Target {
@@ -255,154 +420,112 @@ where
     }
 }
 
-impl<const N: usize> FromIterator<char> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> FromIterator<char> for ArrayString<N, L> {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
         Self::from_chars_truncate(iter)
     }
 }
 
-impl<'a, const N: usize> FromIterator<&'a str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<'a, const N: usize, L: Length> FromIterator<&'a str> for ArrayString<N, L> {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
         Self::from_iterator_truncate(iter)
     }
 }
 
-impl<const N: usize> Extend<char> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Extend<char> for ArrayString<N, L> {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn extend<I: IntoIterator<Item = char>>(&mut self, iterable: I) {
         self.push_str_truncate(Self::from_chars_truncate(iterable))
     }
 }
 
-impl<'a, const N: usize> Extend<&'a char> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<'a, const N: usize, L: Length> Extend<&'a char> for ArrayString<N, L> {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
 }
 
-impl<'a, const N: usize> Extend<&'a str> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<'a, const N: usize, L: Length> Extend<&'a str> for ArrayString<N, L> {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iterable: I) {
         self.push_str_truncate(Self::from_iterator_truncate(iterable))
     }
 }
 
-impl<const N: usize> IndexMut<RangeFrom<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<RangeFrom<L>> for ArrayString<N, L> {
     #[inline]
-    fn index_mut(&mut self, index: RangeFrom<u8>) -> &mut str {
-        let start = index.start as usize;
+    fn index_mut(&mut self, index: RangeFrom<L>) -> &mut str {
+        let start = index.start.to_usize();
         self.as_mut_str().index_mut(RangeFrom { start })
     }
 }
 
-impl<const N: usize> IndexMut<RangeTo<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<RangeTo<L>> for ArrayString<N, L> {
     #[inline]
-    fn index_mut(&mut self, index: RangeTo<u8>) -> &mut str {
-        let end = index.end as usize;
+    fn index_mut(&mut self, index: RangeTo<L>) -> &mut str {
+        let end = index.end.to_usize();
         self.as_mut_str().index_mut(RangeTo { end })
     }
 }
 
-impl<const N: usize> IndexMut<RangeFull> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<RangeFull> for ArrayString<N, L> {
     #[inline]
     fn index_mut(&mut self, index: RangeFull) -> &mut str {
         self.as_mut_str().index_mut(index)
     }
 }
 
-impl<const N: usize> IndexMut<Range<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<Range<L>> for ArrayString<N, L> {
     #[inline]
-    fn index_mut(&mut self, index: Range<u8>) -> &mut str {
-        let (start, end) = (index.start as usize, index.end as usize);
+    fn index_mut(&mut self, index: Range<L>) -> &mut str {
+        let (start, end) = (index.start.to_usize(), index.end.to_usize());
         let range = Range { start, end };
         self.as_mut_str().index_mut(range)
     }
 }
 
-impl<const N: usize> IndexMut<RangeToInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<RangeToInclusive<L>> for ArrayString<N, L> {
     #[inline]
-    fn index_mut(&mut self, index: RangeToInclusive<u8>) -> &mut str {
-        let end = index.end as usize;
+    fn index_mut(&mut self, index: RangeToInclusive<L>) -> &mut str {
+        let end = index.end.to_usize();
         let range = RangeToInclusive { end };
         self.as_mut_str().index_mut(range)
     }
 }
 
-impl<const N: usize> IndexMut<RangeInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> IndexMut<RangeInclusive<L>> for ArrayString<N, L> {
     #[inline]
-    fn index_mut(&mut self, index: RangeInclusive<u8>) -> &mut str {
-        let (start, end) = (*index.start() as usize, *index.end() as usize);
+    fn index_mut(&mut self, index: RangeInclusive<L>) -> &mut str {
+        let (start, end) = (index.start().to_usize(), index.end().to_usize());
         let range = RangeInclusive::new(start, end);
         self.as_mut_str().index_mut(range)
     }
 }
 
-impl<const N: usize> Index<RangeFrom<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<RangeFrom<L>> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
-    fn index(&self, index: RangeFrom<u8>) -> &Self::Output {
-        let start = index.start as usize;
+    fn index(&self, index: RangeFrom<L>) -> &Self::Output {
+        let start = index.start.to_usize();
         self.as_str().index(RangeFrom { start })
     }
 }
 
-impl<const N: usize> Index<RangeTo<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<RangeTo<L>> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
-    fn index(&self, index: RangeTo<u8>) -> &Self::Output {
-        let end = index.end as usize;
+    fn index(&self, index: RangeTo<L>) -> &Self::Output {
+        let end = index.end.to_usize();
         self.as_str().index(RangeTo { end })
     }
 }
 
-impl<const N: usize> Index<RangeFull> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<RangeFull> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
@@ -411,41 +534,32 @@ where
     }
 }
 
-impl<const N: usize> Index<Range<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<Range<L>> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
-    fn index(&self, index: Range<u8>) -> &Self::Output {
-        let (start, end) = (index.start as usize, index.end as usize);
+    fn index(&self, index: Range<L>) -> &Self::Output {
+        let (start, end) = (index.start.to_usize(), index.end.to_usize());
         self.as_str().index(Range { start, end })
     }
 }
 
-impl<const N: usize> Index<RangeToInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<RangeToInclusive<L>> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
-    fn index(&self, index: RangeToInclusive<u8>) -> &Self::Output {
-        let end = index.end as usize;
+    fn index(&self, index: RangeToInclusive<L>) -> &Self::Output {
+        let end = index.end.to_usize();
         self.as_str().index(RangeToInclusive { end })
     }
 }
 
-impl<const N: usize> Index<RangeInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
+impl<const N: usize, L: Length> Index<RangeInclusive<L>> for ArrayString<N, L> {
     type Output = str;
 
     #[inline]
-    fn index(&self, index: RangeInclusive<u8>) -> &Self::Output {
-        let (start, end) = (*index.start() as usize, *index.end() as usize);
+    fn index(&self, index: RangeInclusive<L>) -> &Self::Output {
+        let (start, end) = (index.start().to_usize(), index.end().to_usize());
         let range = RangeInclusive::new(start, end);
         self.as_str().index(range)
     }
@@ -6,7 +6,7 @@ mod diesel_impl {
     #[cfg(all(feature = "no-panic", not(debug_assertions)))]
     use no_panic::no_panic;
 
-    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    pub use crate::{arraystring::sealed::Length, prelude::*};
 
     #[cfg(feature = "std")]
     pub use std::io::Write;
@@ -21,11 +21,10 @@ mod diesel_impl {
         sql_types::*,
     };
 
-    impl<const N: usize, ST, DB> FromSql<ST, DB> for ArrayString<N>
+    impl<const N: usize, L: Length, ST, DB> FromSql<ST, DB> for ArrayString<N, L>
     where
         DB: Backend,
         *const str: FromSql<ST, DB>,
-        Self: ValidCapacity,
     {
         #[inline]
         #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
@@ -36,11 +35,10 @@ mod diesel_impl {
             Ok(Self::from_str_truncate(unsafe { &*ptr }))
         }
     }
-    impl<const N: usize, DB> ToSql<Text, DB> for ArrayString<N>
+    impl<const N: usize, L: Length, DB> ToSql<Text, DB> for ArrayString<N, L>
     where
         DB: Backend,
         str: ToSql<Text, DB>,
-        Self: ValidCapacity,
     {
         #[inline]
         #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
@@ -72,20 +70,45 @@ mod diesel_impl {
             ToSql::<Text, DB>::to_sql(&self.0, out)
         }
     }
+
+    #[cfg(feature = "std")]
+    impl<const N: usize, V: crate::Validator, L: Length, ST, DB> FromSql<ST, DB>
+        for ValidatedArrayString<N, V, L>
+    where
+        DB: Backend,
+        *const str: FromSql<ST, DB>,
+    {
+        #[inline]
+        fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
+            let ptr = <*const str as FromSql<ST, DB>>::from_sql(bytes)?;
+            // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
+            debug_assert!(!ptr.is_null());
+            Ok(Self::try_from_str(unsafe { &*ptr })?)
+        }
+    }
+
+    impl<const N: usize, V, L: Length, DB> ToSql<Text, DB> for ValidatedArrayString<N, V, L>
+    where
+        DB: Backend,
+        str: ToSql<Text, DB>,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
 }
 
 #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "serde-traits")))]
 #[cfg(feature = "serde-traits")]
 mod serde_impl {
-    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    pub use crate::{arraystring::sealed::Length, prelude::*, Validator};
     #[cfg(all(feature = "no-panic", not(debug_assertions)))]
     use no_panic::no_panic;
-    pub use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
+    pub use serde::{de::Deserializer, de::Error, ser::Serializer, Deserialize, Serialize};
 
-    impl<const N: usize> Serialize for ArrayString<N>
-    where
-        Self: ValidCapacity,
-    {
+    impl<const N: usize, L: Length> Serialize for ArrayString<N, L> {
         #[inline]
         #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
         fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
@@ -93,10 +116,7 @@ mod serde_impl {
         }
     }
 
-    impl<'a, const N: usize> Deserialize<'a> for ArrayString<N>
-    where
-        Self: ValidCapacity,
-    {
+    impl<'a, const N: usize, L: Length> Deserialize<'a> for ArrayString<N, L> {
         #[inline]
         #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
         fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
@@ -119,6 +139,23 @@ mod serde_impl {
             Ok(CacheString(Deserialize::deserialize(des)?))
         }
     }
+
+    impl<const N: usize, V, L: Length> Serialize for ValidatedArrayString<N, V, L> {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            Serialize::serialize(self.as_str(), ser)
+        }
+    }
+
+    impl<'a, const N: usize, V: Validator, L: Length> Deserialize<'a>
+        for ValidatedArrayString<N, V, L>
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
+            Self::try_from_str(<&str>::deserialize(des)?).map_err(D::Error::custom)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -179,6 +216,31 @@ mod tests {
         assert_eq!(s, ArrayString::<8>::try_from_str("abcdefg").unwrap());
     }
 
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_validated_json() {
+        use crate::validator::{Ascii, NonEmpty};
+
+        type Code = ValidatedArrayString<3, (NonEmpty, Ascii)>;
+        let code: Code = serde_json::from_str("\"BRL\"").unwrap();
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"BRL\"");
+        assert!(serde_json::from_str::<Code>("\"\"").is_err());
+        assert!(serde_json::from_str::<Code>("\"Ré\"").is_err());
+        assert!(serde_json::from_str::<Code>("\"EURO\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_fixed_json() {
+        let code: FixedString<3> = serde_json::from_str("\"BRL\"").unwrap();
+        assert_eq!(code.as_str(), "BRL");
+        assert!(serde_json::from_str::<FixedString<3>>("\"BR\"").is_err());
+        assert!(serde_json::from_str::<FixedString<3>>("\"EURO\"").is_err());
+        assert!(serde_json::from_str::<BoundedString<8, 64>>("\"hunter2\"").is_err());
+    }
+
+    #[cfg(all(feature = "diesel-traits", feature = "std"))]
+    use crate::validator::Alphanumeric;
     #[cfg(all(feature = "diesel-traits", feature = "std"))]
     use diesel::{dsl, mysql, pg, prelude::*};
 
@@ -214,6 +276,14 @@ mod tests {
         pub name: &'a str,
     }
 
+    #[cfg(all(feature = "diesel-traits", feature = "std"))]
+    #[derive(AsChangeset, Identifiable, Queryable, QueryableByName, Insertable, Clone, Debug)]
+    #[diesel(table_name = derives)]
+    struct Derive4Diesel {
+        pub id: i32,
+        pub name: ValidatedArrayString<32, Alphanumeric>,
+    }
+
     #[test]
     #[ignore]
     #[cfg(all(feature = "diesel-traits", feature = "std"))]
@@ -302,4 +372,35 @@ mod tests {
         let queried: Derive2Diesel = derives::table.first(&mut conn).unwrap();
         assert_eq!(queried.name.as_str(), "Name1");
     }
+
+    #[test]
+    #[cfg(all(feature = "diesel-traits", feature = "std", not(miri)))]
+    fn diesel_validated_query_sqlite() {
+        let mut conn = diesel::sqlite::SqliteConnection::establish(":memory:").unwrap();
+        let _ = diesel::sql_query("CREATE TABLE derives (id INTEGER, name VARCHAR(32));")
+            .execute(&mut conn)
+            .unwrap();
+        let string = Derive4Diesel {
+            id: 0,
+            name: ValidatedArrayString::try_from_str("Name1").unwrap(),
+        };
+
+        let _ = diesel::insert_into(derives::table)
+            .values(&string)
+            .execute(&mut conn)
+            .unwrap();
+
+        let queried: Derive4Diesel = derives::table.first(&mut conn).unwrap();
+        assert_eq!(queried.name.as_str(), "Name1");
+
+        let _ = diesel::insert_into(derives::table)
+            .values(&Derive3Diesel {
+                id: 1,
+                name: "Name 2",
+            })
+            .execute(&mut conn)
+            .unwrap();
+        let queried: Result<Vec<Derive4Diesel>, _> = derives::table.load(&mut conn);
+        assert!(queried.is_err());
+    }
 }
@@ -2,9 +2,9 @@
 //!
 //! Can't outgrow initial capacity (defined at compile time), always occupies [`capacity`] `+ 1` bytes of memory
 //!
-//! *Maximum Capacity is 255*
+//! *Capacities above 255 bytes need a wider length type: `ArrayString<1024, u16>` (up to 65535 bytes) or `ArrayString<100_000, usize>`, occupying [`capacity`] `+ size_of::<u16>()` or [`capacity`] `+ size_of::<usize>()` bytes*
 //!
-//! *Doesn't allocate memory on the heap and should never panic in release (except in `Index`/`IndexMut` traits, since they are supposed to)*
+//! *Doesn't allocate memory on the heap and should never panic in release (except in `Index`/`IndexMut` traits, since they are supposed to, and in [`from_static`] outside of const contexts, where it fails to compile instead)*
 //!
 //! *The no panic garantee can be ensured at compilation time with the `no-panic` feature, just be aware that a compiler update might break this garantee, therefore making the crate uncompilable, open an issue if you notice.*
 //!
@@ -16,7 +16,7 @@
 //!
 //! Stack based strings are generally faster to create, clone and append to than heap based strings (custom allocators and thread-locals may help with heap based ones).
 //!
-//! But that becomes less true as you increase the array size, [`CacheString`] occupies a full cache line and 255 bytes is the maximum we accept ([`MaxString`] and it's probably already slower than heap based strings of that size - like in `std::string::String`)
+//! But that becomes less true as you increase the array size, [`CacheString`] occupies a full cache line and 255 bytes is the biggest capacity with a single byte length ([`MaxString`] and it's probably already slower than heap based strings of that size - like in `std::string::String`). Bigger buffers, like a 4 KiB path, can still live in the stack with a `u16` length: `ArrayString<4096, u16>`
 //!
 //! There are other stack based strings out there, they generally don't use stable const generics and a lot of them only support stack based strings in the context of small string optimizations.
 //!
@@ -24,19 +24,23 @@
 //!
 //! [`capacity`]: ./struct.ArrayString.html#method.capacity
 //! [`MaxString`]: ./type.MaxString.html
+//! [`from_static`]: ./struct.ArrayString.html#method.from_static
 //!
 //! ## Features
 //!
 //! **default:** `std`
 //!
-//! - `std` enabled by default, enables `std` compatibility, implementing std only traits (disable it to be `#[no_std]` compatible)
+//! - `std` enabled by default, enables `std` compatibility, implementing std only traits like `io::Write` and `std::error::Error` (disable it to be `#[no_std]` compatible)
+//! - `alloc` enabled by `std`, enables conversions and comparisons with `String`, `Box<str>`, `Cow<str>`, `Rc<str>` and `Arc<str>` in `#[no_std]` environments with an allocator
 //! - `serde-traits` enables serde traits integration (`Serialize`/`Deserialize`)
 //!
-//!     Opperates like `String`, but truncates it if it's bigger than capacity
+//!     Opperates like `String`, but truncates it if it's bigger than capacity, `ValidatedArrayString`, `BoundedString` and `FixedString` reject invalid or oversized input instead
 //!
 //! - `diesel-traits` enables diesel traits integration
 //!
-//!      Opperates like `String`, but truncates it if it's bigger than capacity
+//!      Opperates like `String`, but truncates it if it's bigger than capacity, `ValidatedArrayString`, `BoundedString` and `FixedString` reject invalid or oversized input instead
+//!
+//! - `unicode-segmentation` enables truncating by extended grapheme clusters (`truncate_graphemes`), so emoji sequences and combining accents aren't split
 //!
 //! - `no-panic` checks at compile time that the panic function is not linked by the library
 //!
@@ -96,7 +100,6 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
     unconditional_recursion,
     unused_allocation,
     unused_comparisons,
@@ -105,6 +108,9 @@
 )]
 #![doc(test(attr(deny(warnings))))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Remove logging macros when they are disabled (at compile time)
 #[macro_use]
 #[cfg(not(feature = "logs"))]
@@ -118,39 +124,141 @@ mod mock {
 }
 
 mod arraystring;
+mod ascii;
 mod drain;
 pub mod error;
 mod implementations;
 #[cfg(any(feature = "serde-traits", feature = "diesel-traits"))]
 mod integration;
+mod line;
+mod macros;
+mod parts;
+mod pattern;
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+mod reader;
+mod truncating;
 #[doc(hidden)]
 pub mod utils;
+mod validated;
+pub mod validator;
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+mod writer;
 
 /// All structs defined by this crate
 pub mod prelude {
     pub use crate::arraystring::ArrayString;
+    pub use crate::ascii::{AsciiArrayString, AsciiChars};
     pub use crate::drain::Drain;
-    pub use crate::error::{OutOfBounds, Utf16, Utf8};
-    pub use crate::{CacheString, MaxString, SmallString};
+    pub use crate::error::{Invalid, InvalidRange, NotAscii, NotCharBoundary};
+    pub use crate::error::{OutOfBounds, OutOfBoundsWith};
+    pub use crate::error::{Utf16, Utf8};
+    pub use crate::line::ReadLine;
+    pub use crate::parts::Parts;
+    #[cfg(feature = "std")]
+    pub use crate::reader::Reader;
+    pub use crate::truncating::Truncating;
+    pub use crate::validated::ValidatedArrayString;
+    #[cfg(feature = "std")]
+    pub use crate::writer::Writer;
+    pub use crate::{BoundedString, CacheString, FixedString, MaxString, SmallString};
 }
 
+pub use crate::arraystring::sealed::Length;
 pub use crate::arraystring::ArrayString;
+pub use crate::ascii::{AsciiArrayString, AsciiChars};
 pub use crate::drain::Drain;
 pub use crate::error::Error;
+pub use crate::line::ReadLine;
+pub use crate::parts::Parts;
+pub use crate::pattern::Pattern;
+#[cfg(feature = "std")]
+pub use crate::reader::Reader;
+pub use crate::truncating::Truncating;
+pub use crate::validated::ValidatedArrayString;
+pub use crate::validator::Validator;
+#[cfg(feature = "std")]
+pub use crate::writer::Writer;
 
 /// String with the same `core::mem::size_of` of a `String` (`core::mem::size_of::<usize> * 3`)
 ///
 /// 24 bytes in 64 bits architecture
 ///
 /// 12 bytes in 32 bits architecture
-pub type SmallString = ArrayString<{ core::mem::size_of::<usize>() * 3 }>;
+pub type SmallString = ArrayString<{ size_of::<usize>() * 3 }>;
 
-/// Biggest `ArrayString<N>` supported (255 bytes of text)
+/// Biggest `ArrayString<N>` with a single byte length (255 bytes of text)
 pub type MaxString = ArrayString<255>;
 
+/// String with at least `MIN` and at most `MAX` bytes of text
+///
+/// A [`ValidatedArrayString`] whose invariant is the minimum length, so construction, `pop`, `truncate`, `clear` and `drain` fail instead of going below `MIN`. `MIN > MAX` fails to compile.
+///
+/// [`ValidatedArrayString`]: ./struct.ValidatedArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type Password = BoundedString<8, 64>;
+///
+/// let mut password = Password::try_from_str("hunter22")?;
+/// assert!(matches!(password.pop(), Err(_)));
+/// assert!(matches!(password.truncate(4), Err(Error::Invalid(_))));
+/// assert!(matches!(password.drain(..1), Err(Error::Invalid(_))));
+/// assert!(password.clear().is_err());
+/// assert_eq!(password.as_str(), "hunter22");
+///
+/// password.try_push('!')?;
+/// assert_eq!(password.pop()?, Some('!'));
+///
+/// assert!(matches!(Password::try_from_str("hunter2"), Err(Error::Invalid(_))));
+/// assert!(matches!(Password::try_from_str("0".repeat(65)), Err(Error::OutOfBounds(_))));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use arraystring::prelude::*;
+/// // No string can have at least 8 bytes and at most 4
+/// let string = BoundedString::<8, 4>::try_from_str("abcdef");
+/// ```
+pub type BoundedString<const MIN: usize, const MAX: usize, L = u8> =
+    ValidatedArrayString<MAX, validator::MinLen<MIN>, L>;
+
+/// String with exactly `N` bytes of text
+///
+/// A [`BoundedString`] with the same minimum and maximum length, every mutation has to keep the length unchanged.
+///
+/// [`BoundedString`]: ./type.BoundedString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type CurrencyCode = FixedString<3>;
+///
+/// let mut code = CurrencyCode::try_from_str("brl")?;
+/// code.make_ascii_uppercase()?;
+/// code.replace_range(..1, "E")?;
+/// assert_eq!(code.as_str(), "ERL");
+/// assert!(code.pop().is_err());
+/// assert!(code.replace_range(..1, "").is_err());
+///
+/// assert!(matches!(CurrencyCode::try_from_str("BR"), Err(Error::Invalid(_))));
+/// assert!(matches!(CurrencyCode::try_from_str("EURO"), Err(Error::OutOfBounds(_))));
+/// # Ok(())
+/// # }
+/// ```
+pub type FixedString<const N: usize, L = u8> = BoundedString<N, N, L>;
+
 mod cache_string {
-    use crate::{prelude::*, Error};
+    use crate::{arraystring::sealed::Length, prelude::*, Error};
+    #[cfg(feature = "alloc")]
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc};
     use core::fmt::{self, Debug, Display, Formatter, Write};
+    use core::iter::{Chain, Once};
     use core::{borrow::Borrow, borrow::BorrowMut, ops::*};
     use core::{cmp::Ordering, hash::Hash, hash::Hasher, str::FromStr};
 
@@ -181,6 +289,24 @@ mod cache_string {
             Self(ArrayString::<CACHE_STRING_SIZE>::new())
         }
 
+        /// Creates new `CacheString` from a string literal, in a const context it fails to compile if the literal is bigger than [`capacity`]
+        ///
+        /// Meant for `const` and `static` items, if called at runtime it panics instead, prefer [`try_from_str`] there.
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        /// [`try_from_str`]: ./struct.CacheString.html#method.try_from_str
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// static GREETING: CacheString = CacheString::from_static("Hello");
+        /// assert_eq!(GREETING.as_str(), "Hello");
+        /// ```
+        #[inline]
+        pub const fn from_static(string: &'static str) -> Self {
+            Self(ArrayString::from_static(string))
+        }
+
         /// Creates new `CacheString` from string slice if length is lower or equal to [`capacity`], otherwise returns an error.
         ///
         /// [`capacity`]: ./struct.CacheString.html#method.capacity
@@ -224,6 +350,32 @@ mod cache_string {
             Self(ArrayString::from_str_truncate(string))
         }
 
+        /// Creates new `CacheString` from string slice assuming length is appropriate.
+        ///
+        /// # Safety
+        ///
+        /// It's UB if `string.len()` > [`capacity`].
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let filled = "0".repeat(CacheString::capacity());
+        /// let string = unsafe {
+        ///     CacheString::from_str_unchecked(&filled)
+        /// };
+        /// assert_eq!(string.as_str(), filled.as_str());
+        ///
+        /// // Undefined behavior, don't do it
+        /// // let out_of_bounds = "0".repeat(CacheString::capacity() + 1);
+        /// // let ub = unsafe { CacheString::from_str_unchecked(out_of_bounds) };
+        /// ```
+        #[inline]
+        pub unsafe fn from_str_unchecked(string: impl AsRef<str>) -> Self {
+            Self(ArrayString::from_str_unchecked(string))
+        }
+
         /// Creates new `CacheString` from string slice iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
         ///
         /// [`capacity`]: ./struct.CacheString.html#method.capacity
@@ -246,6 +398,28 @@ mod cache_string {
             Ok(Self(ArrayString::try_from_iterator(iter)?))
         }
 
+        /// Creates new `CacheString` from string slice iterator, if an item doesn't fit returns what was built with the leftover items
+        ///
+        /// Items are never split, the first leftover is the item that didn't fit, followed by the ones that weren't consumed.
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let words = (0..20).map(|_| "word ");
+        /// let (line, leftover) = CacheString::try_from_iterator_partial(words).unwrap_err();
+        /// assert_eq!(line.as_str(), "word ".repeat(12));
+        /// assert_eq!(leftover.count(), 8);
+        /// ```
+        #[inline]
+        #[allow(clippy::type_complexity)]
+        pub fn try_from_iterator_partial<S: AsRef<str>, I: IntoIterator<Item = S>>(
+            iter: I,
+        ) -> Result<Self, (Self, Chain<Once<S>, I::IntoIter>)> {
+            ArrayString::try_from_iterator_partial(iter)
+                .map(Self)
+                .map_err(|(string, leftover)| (Self(string), leftover))
+        }
+
         /// Creates new `CacheString` from string slice iterator truncating size if bigger than [`capacity`].
         ///
         /// [`capacity`]: ./struct.CacheString.html#method.capacity
@@ -291,6 +465,28 @@ mod cache_string {
             Ok(Self(ArrayString::try_from_chars(iter)?))
         }
 
+        /// Creates new `CacheString` from char iterator, if a char doesn't fit returns what was built with the leftover chars
+        ///
+        /// The first leftover is the char that didn't fit, followed by the ones that weren't consumed.
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let text = "0".repeat(CacheString::capacity() - 1) + "🤔";
+        /// let (chunk, leftover) = CacheString::try_from_chars_partial(text.chars()).unwrap_err();
+        /// assert_eq!(chunk.len(), CacheString::capacity() - 1);
+        /// assert_eq!(leftover.collect::<String>(), "🤔");
+        /// ```
+        #[inline]
+        #[allow(clippy::type_complexity)]
+        pub fn try_from_chars_partial<I: IntoIterator<Item = char>>(
+            iter: I,
+        ) -> Result<Self, (Self, Chain<Once<char>, I::IntoIter>)> {
+            ArrayString::try_from_chars_partial(iter)
+                .map(Self)
+                .map_err(|(string, leftover)| (Self(string), leftover))
+        }
+
         /// Creates new `CacheString` from char iterator truncating size if bigger than [`capacity`].
         ///
         /// [`capacity`]: ./struct.CacheString.html#method.capacity
@@ -312,6 +508,106 @@ mod cache_string {
             Self(ArrayString::from_chars_truncate(iter))
         }
 
+        /// Creates new `CacheString` from byte slice, returning [`Utf8`] on invalid utf-8 data or [`OutOfBounds`] if bigger than [`capacity`]
+        ///
+        /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+        /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = CacheString::try_from_utf8("My String")?;
+        /// assert_eq!(string.as_str(), "My String");
+        ///
+        /// let invalid_utf8 = [0, 159, 146, 150];
+        /// assert!(matches!(CacheString::try_from_utf8(invalid_utf8), Err(Error::Utf8(_))));
+        ///
+        /// let out_of_bounds = "0".repeat(300);
+        /// assert!(matches!(CacheString::try_from_utf8(out_of_bounds.as_bytes()), Err(Error::OutOfBounds(_))));
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn try_from_utf8(slice: impl AsRef<[u8]>) -> Result<Self, Error> {
+            Ok(Self(ArrayString::try_from_utf8(slice)?))
+        }
+
+        /// Creates new `CacheString` from byte slice, returning [`Utf8`] on invalid utf-8 data, truncating if bigger than [`capacity`].
+        ///
+        /// [`Utf8`]: ./error/struct.Utf8.html
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = CacheString::from_utf8_truncate("My String")?;
+        /// assert_eq!(string.as_str(), "My String");
+        ///
+        /// let invalid_utf8 = [0, 159, 146, 150];
+        /// assert!(matches!(CacheString::from_utf8_truncate(invalid_utf8), Err(Utf8 { .. })));
+        ///
+        /// let out_of_bounds = "0".repeat(300);
+        /// assert_eq!(CacheString::from_utf8_truncate(out_of_bounds)?.as_str(),
+        ///            "0".repeat(CacheString::capacity()).as_str());
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn from_utf8_truncate(slice: impl AsRef<[u8]>) -> Result<Self, Utf8> {
+            Ok(Self(ArrayString::from_utf8_truncate(slice)?))
+        }
+
+        /// Creates new `CacheString` from byte slice, replacing invalid utf-8 data with `REPLACEMENT_CHARACTER` (\u{FFFD}) and truncating size if bigger than [`capacity`]
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = CacheString::from_utf8_lossy_truncate("My String");
+        /// assert_eq!(string.as_str(), "My String");
+        ///
+        /// let invalid_utf8 = b"Hello \xF0\x90\x80World";
+        /// assert_eq!(CacheString::from_utf8_lossy_truncate(invalid_utf8).as_str(), "Hello \u{FFFD}World");
+        ///
+        /// let out_of_bounds = "0".repeat(300);
+        /// assert_eq!(CacheString::from_utf8_lossy_truncate(out_of_bounds).as_str(),
+        ///            "0".repeat(CacheString::capacity()).as_str());
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn from_utf8_lossy_truncate(slice: impl AsRef<[u8]>) -> Self {
+            Self(ArrayString::from_utf8_lossy_truncate(slice))
+        }
+
+        /// Creates new `CacheString` from byte slice assuming it's utf-8 and of a appropriate size.
+        ///
+        /// # Safety
+        ///
+        /// It's UB if `slice` is not a valid utf-8 string or `slice.len()` > [`capacity`].
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = unsafe { CacheString::from_utf8_unchecked("My String") };
+        /// assert_eq!(string.as_str(), "My String");
+        ///
+        /// // Undefined behavior, don't do it
+        /// // let out_of_bounds = "0".repeat(300);
+        /// // let ub = unsafe { CacheString::from_utf8_unchecked(out_of_bounds) };
+        /// ```
+        #[inline]
+        pub unsafe fn from_utf8_unchecked(slice: impl AsRef<[u8]>) -> Self {
+            Self(ArrayString::from_utf8_unchecked(slice))
+        }
+
         /// Creates new `CacheString` from `u16` slice, returning [`Utf16`] on invalid utf-16 data or [`OutOfBounds`] if bigger than [`capacity`]
         ///
         /// [`Utf16`]: ./error/enum.Error.html#variant.Utf16
@@ -327,10 +623,10 @@ mod cache_string {
         /// assert_eq!(string.as_str(), "𝄞music");
         ///
         /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-        /// assert_eq!(CacheString::try_from_utf16(invalid_utf16), Err(Error::Utf16));
+        /// assert!(matches!(CacheString::try_from_utf16(invalid_utf16), Err(Error::Utf16(_))));
         ///
         /// let out_of_bounds: Vec<_> = (0..300).map(|_| 0).collect();
-        /// assert_eq!(CacheString::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds));
+        /// assert!(matches!(CacheString::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds(_))));
         /// # Ok(())
         /// # }
         /// ```
@@ -353,7 +649,7 @@ mod cache_string {
         /// assert_eq!(string.as_str(), "𝄞music");
         ///
         /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-        /// assert_eq!(CacheString::from_utf16_truncate(invalid_utf16), Err(Utf16));
+        /// assert!(matches!(CacheString::from_utf16_truncate(invalid_utf16), Err(Utf16 { .. })));
         ///
         /// let out_of_bounds: Vec<u16> = (0..300).map(|_| 0).collect();
         /// assert_eq!(CacheString::from_utf16_truncate(out_of_bounds)?.as_str(),
@@ -392,6 +688,42 @@ mod cache_string {
             Self(ArrayString::from_utf16_lossy_truncate(slice))
         }
 
+        /// Creates new `CacheString` from formatting arguments if the formatted length is lower or equal to [`capacity`], otherwise returns an error.
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # fn main() -> Result<(), OutOfBounds> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = CacheString::try_from_fmt(format_args!("{} + {} = {}", 1, 2, 1 + 2))?;
+        /// assert_eq!(string.as_str(), "1 + 2 = 3");
+        ///
+        /// let out_of_bounds = CacheString::try_from_fmt(format_args!("{:>100}", 1));
+        /// assert!(matches!(out_of_bounds, Err(OutOfBounds { .. })));
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn try_from_fmt(args: fmt::Arguments) -> Result<Self, OutOfBounds> {
+            Ok(Self(ArrayString::try_from_fmt(args)?))
+        }
+
+        /// Creates new `CacheString` from formatting arguments truncating size if bigger than [`capacity`].
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = CacheString::from_fmt_truncate(format_args!("{:0>100}", 1));
+        /// assert_eq!(string.as_str(), "0".repeat(CacheString::capacity()));
+        /// ```
+        #[inline]
+        pub fn from_fmt_truncate(args: fmt::Arguments) -> Self {
+            Self(ArrayString::from_fmt_truncate(args))
+        }
+
         /// Returns maximum string capacity, defined at compile time, it will never change
         ///
         /// Should always return 63 bytes
@@ -408,10 +740,10 @@ mod cache_string {
 
         /// Splits `CacheString` in two if `at` is smaller than `self.len()`.
         ///
-        /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
+        /// Returns [`NotCharBoundary`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
         ///
         /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-        /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+        /// [`NotCharBoundary`]: ./error/enum.Error.html#variant.NotCharBoundary
         ///
         /// ```rust
         /// # use arraystring::{Error, prelude::*};
@@ -420,8 +752,8 @@ mod cache_string {
         /// let mut s = CacheString::try_from_str("AB🤔CD")?;
         /// assert_eq!(s.split_off(6)?.as_str(), "CD");
         /// assert_eq!(s.as_str(), "AB🤔");
-        /// assert_eq!(s.split_off(20), Err(Error::OutOfBounds));
-        /// assert_eq!(s.split_off(4), Err(Error::Utf8));
+        /// assert!(matches!(s.split_off(20), Err(Error::OutOfBounds(_))));
+        /// assert!(matches!(s.split_off(4), Err(Error::NotCharBoundary(_))));
         /// # Ok(())
         /// # }
         /// ```
@@ -429,6 +761,106 @@ mod cache_string {
         pub fn split_off(&mut self, at: usize) -> Result<Self, Error> {
             Ok(Self(self.0.split_off(at)?))
         }
+
+        /// Creates new `CacheString` with the uppercase equivalent of this one, returning [`OutOfBounds`] if it's bigger than [`capacity`]
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let s = CacheString::try_from_str("straße")?;
+        /// assert_eq!(s.try_to_uppercase()?, "STRASSE");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn try_to_uppercase(&self) -> Result<Self, OutOfBounds> {
+            Ok(Self(self.0.try_to_uppercase()?))
+        }
+
+        /// Creates new `CacheString` with the uppercase equivalent of this one, truncating at the last char that fits in [`capacity`]
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let s = CacheString::try_from_str("straße")?;
+        /// assert_eq!(s.to_uppercase_truncate(), "STRASSE");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn to_uppercase_truncate(&self) -> Self {
+            Self(self.0.to_uppercase_truncate())
+        }
+
+        /// Creates new `CacheString` with the lowercase equivalent of this one, returning [`OutOfBounds`] if it's bigger than [`capacity`]
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let s = CacheString::try_from_str("User@Example.COM")?;
+        /// assert_eq!(s.try_to_lowercase()?, "user@example.com");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn try_to_lowercase(&self) -> Result<Self, OutOfBounds> {
+            Ok(Self(self.0.try_to_lowercase()?))
+        }
+
+        /// Creates new `CacheString` with the lowercase equivalent of this one, truncating at the last char that fits in [`capacity`]
+        ///
+        /// [`capacity`]: ./struct.CacheString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let s = CacheString::try_from_str("User@Example.COM")?;
+        /// assert_eq!(s.to_lowercase_truncate(), "user@example.com");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn to_lowercase_truncate(&self) -> Self {
+            Self(self.0.to_lowercase_truncate())
+        }
+
+        /// Creates new `CacheString` with ASCII letters converted to uppercase, leaving other chars unchanged
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// const LOUD: CacheString = CacheString::from_static("grüße").to_ascii_uppercase();
+        /// assert_eq!(LOUD, "GRüßE");
+        /// ```
+        #[inline]
+        pub const fn to_ascii_uppercase(&self) -> Self {
+            Self(self.0.to_ascii_uppercase())
+        }
+
+        /// Creates new `CacheString` with ASCII letters converted to lowercase, leaving other chars unchanged
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// const DOMAIN: CacheString = CacheString::from_static("Example.COM").to_ascii_lowercase();
+        /// assert_eq!(DOMAIN, "example.com");
+        /// ```
+        #[inline]
+        pub const fn to_ascii_lowercase(&self) -> Self {
+            Self(self.0.to_ascii_lowercase())
+        }
     }
 
     impl Debug for CacheString {
@@ -550,18 +982,53 @@ mod cache_string {
         }
     }
 
-    impl PartialEq<str> for CacheString {
-        #[inline]
-        fn eq(&self, other: &str) -> bool {
-            self.0.eq(other)
-        }
+    /// Implements symmetric `PartialEq` and `PartialOrd` between `CacheString` and types `ArrayString` can be compared to
+    macro_rules! impl_comparisons {
+        ($($(#[$attr:meta])* [$($generics:tt)*] $other:ty;)*) => {$(
+            $(#[$attr])*
+            impl<$($generics)*> PartialEq<$other> for CacheString {
+                #[inline]
+                fn eq(&self, other: &$other) -> bool {
+                    self.0.eq(other)
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)*> PartialEq<CacheString> for $other {
+                #[inline]
+                fn eq(&self, other: &CacheString) -> bool {
+                    self.eq(&other.0)
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)*> PartialOrd<$other> for CacheString {
+                #[inline]
+                fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                    self.0.partial_cmp(other)
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)*> PartialOrd<CacheString> for $other {
+                #[inline]
+                fn partial_cmp(&self, other: &CacheString) -> Option<Ordering> {
+                    self.partial_cmp(&other.0)
+                }
+            }
+        )*};
     }
 
-    impl PartialEq<&str> for CacheString {
-        #[inline]
-        fn eq(&self, other: &&str) -> bool {
-            self.0.eq(other)
-        }
+    impl_comparisons! {
+        [] str;
+        ['a] &'a str;
+        [const N: usize, L: Length] ArrayString<N, L>;
+        #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+        #[cfg(feature = "alloc")]
+        [] String;
+        #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+        #[cfg(feature = "alloc")]
+        ['a] Cow<'a, str>;
     }
 
     impl Borrow<str> for CacheString {
@@ -577,7 +1044,7 @@ mod cache_string {
         }
     }
 
-    impl<'a> Add<&'a str> for CacheString {
+    impl Add<&str> for CacheString {
         type Output = Self;
 
         #[inline]
@@ -593,12 +1060,98 @@ mod cache_string {
         }
     }
 
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    impl std::io::Write for CacheString {
+        #[inline]
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        #[inline]
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.0.flush()
+        }
+    }
+
     impl From<ArrayString<CACHE_STRING_SIZE>> for CacheString {
         fn from(array: ArrayString<CACHE_STRING_SIZE>) -> Self {
             Self(array)
         }
     }
 
+    impl From<CacheString> for ArrayString<CACHE_STRING_SIZE> {
+        #[inline]
+        fn from(string: CacheString) -> Self {
+            string.0
+        }
+    }
+
+    impl<const N: usize, L: Length> TryFrom<&ArrayString<N, L>> for CacheString {
+        type Error = OutOfBounds;
+
+        #[inline]
+        fn try_from(string: &ArrayString<N, L>) -> Result<Self, Self::Error> {
+            Ok(Self(ArrayString::try_from(string)?))
+        }
+    }
+
+    impl<const N: usize, L: Length> TryFrom<&CacheString> for ArrayString<N, L> {
+        type Error = OutOfBounds;
+
+        #[inline]
+        fn try_from(string: &CacheString) -> Result<Self, Self::Error> {
+            Self::try_from(&string.0)
+        }
+    }
+
+    /// Implements `TryFrom` for owned strings, giving them back if they don't fit
+    macro_rules! impl_try_from_owned {
+        ($([$($lt:lifetime)?] $owned:ty;)*) => {$(
+            #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+            #[cfg(feature = "alloc")]
+            impl<$($lt)?> TryFrom<$owned> for CacheString {
+                type Error = OutOfBoundsWith<$owned>;
+
+                #[inline]
+                fn try_from(string: $owned) -> Result<Self, Self::Error> {
+                    Ok(Self(ArrayString::try_from(string)?))
+                }
+            }
+        )*};
+    }
+
+    impl_try_from_owned! {
+        [] String;
+        [] Box<str>;
+        ['a] Cow<'a, str>;
+    }
+
+    /// Implements `From<CacheString>` for heap allocated strings
+    macro_rules! impl_into_allocated {
+        ($($allocated:ty),*) => {$(
+            #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+            #[cfg(feature = "alloc")]
+            impl From<CacheString> for $allocated {
+                #[inline]
+                fn from(string: CacheString) -> Self {
+                    Self::from(string.0)
+                }
+            }
+        )*};
+    }
+
+    impl_into_allocated!(String, Box<str>, Rc<str>, Arc<str>);
+
+    #[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    impl<'a> From<&'a CacheString> for Cow<'a, str> {
+        #[inline]
+        fn from(string: &'a CacheString) -> Self {
+            Cow::from(&string.0)
+        }
+    }
+
     impl From<&str> for CacheString {
         fn from(s: &str) -> Self {
             Self(ArrayString::<CACHE_STRING_SIZE>::from(s))
@@ -707,6 +1260,17 @@ pub use cache_string::*;
 mod tests {
     #[test]
     fn size_of_cache() {
-        assert_eq!(core::mem::size_of::<super::CacheString>(), 64);
+        assert_eq!(size_of::<super::CacheString>(), 64);
+    }
+
+    #[test]
+    fn const_length_types() {
+        use super::ArrayString;
+        const BYTE: ArrayString<255> = ArrayString::from_static("abc");
+        const SHORT: ArrayString<1024, u16> = ArrayString::from_static("abcd");
+        const WORD: ArrayString<70_000, usize> = ArrayString::from_static("abcde");
+        assert_eq!((BYTE.as_str(), BYTE.len()), ("abc", 3));
+        assert_eq!((SHORT.as_str(), SHORT.len()), ("abcd", 4));
+        assert_eq!((WORD.as_str(), WORD.len()), ("abcde", 5));
     }
 }
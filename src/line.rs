@@ -0,0 +1,113 @@
+//! Outcome of reading a line into an [`ArrayString`]
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::utils::utf8_char_width;
+use crate::{arraystring::sealed::Length, prelude::*};
+use core::str;
+#[cfg(feature = "logs")]
+use log::trace;
+
+/// How much of a line was read into an [`ArrayString`]
+///
+/// Returned by [`read_line_from`], [`read_line_from_truncate`] and [`read_line_from_bytes_truncate`]
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`read_line_from`]: ./struct.ArrayString.html#method.read_line_from
+/// [`read_line_from_truncate`]: ./struct.ArrayString.html#method.read_line_from_truncate
+/// [`read_line_from_bytes_truncate`]: ./struct.ArrayString.html#method.read_line_from_bytes_truncate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReadLine {
+    /// The whole line was appended, including the newline if there was one. `0` bytes means the input ended
+    Complete(usize),
+    /// The string got full, the appended bytes are returned and the rest of the line is left in the reader
+    Overflowed(usize),
+    /// The string got full, the rest of the line was consumed and discarded
+    Truncated {
+        /// Bytes appended to the string
+        kept: usize,
+        /// Bytes of the line that didn't fit, including the newline if there was one
+        dropped: usize,
+    },
+}
+
+/// Start of a char that was split between two chunks of input
+#[derive(Default)]
+pub(crate) struct PartialChar {
+    bytes: [u8; 4],
+    len: usize,
+}
+
+impl PartialChar {
+    /// Returns true if no char is waiting for the rest of its bytes
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Appends utf-8 bytes until the string is full, keeping a char split at the end of `bytes` in `partial`
+///
+/// Returns how many bytes were consumed, less than `bytes.len()` means the string is full
+#[inline]
+pub(crate) fn push_utf8<const N: usize, L: Length>(
+    string: &mut ArrayString<N, L>,
+    partial: &mut PartialChar,
+    bytes: &[u8],
+) -> Result<usize, Utf8> {
+    trace!("Push utf8: {bytes:?} to {string}");
+    let mut consumed = 0;
+    if partial.len > 0 {
+        let width = partial.bytes.first().copied().map_or(0, utf8_char_width);
+        let missing = width.saturating_sub(partial.len).min(bytes.len());
+        let (Some(dst), Some(src)) = (
+            partial.bytes.get_mut(partial.len..partial.len + missing),
+            bytes.get(..missing),
+        ) else {
+            return Err(Utf8::new(0, None));
+        };
+        dst.copy_from_slice(src);
+        partial.len += missing;
+        consumed = missing;
+        let sequence = partial.bytes.get(..partial.len).unwrap_or_default();
+        match str::from_utf8(sequence) {
+            // Fits, since it was only kept if there was space for it
+            Ok(ch) => string.push_str_truncate(ch),
+            Err(err) if err.error_len().is_none() => return Ok(consumed),
+            Err(err) => return Err(err.into()),
+        }
+        partial.len = 0;
+    }
+
+    let rest = bytes.get(consumed..).unwrap_or_default();
+    let (valid, incomplete, invalid) = match str::from_utf8(rest) {
+        Ok(valid) => (valid, Default::default(), None),
+        Err(err) => {
+            let (valid, tail) = rest.split_at(err.valid_up_to());
+            // Safety: `valid_up_to` bytes were validated as utf-8 above
+            let valid = unsafe { str::from_utf8_unchecked(valid) };
+            match err.error_len() {
+                None => (valid, tail, None),
+                Some(_) => (valid, Default::default(), Some(Utf8::from(err))),
+            }
+        }
+    };
+    let len = string.len();
+    string.push_str_truncate(valid);
+    let pushed = string.len() - len;
+    if pushed < valid.len() {
+        return Ok(consumed + pushed);
+    }
+    if let Some(err) = invalid {
+        return Err(err.with_valid_up_to(consumed + err.valid_up_to()));
+    }
+    let width = incomplete.first().copied().map_or(0, utf8_char_width);
+    match partial.bytes.get_mut(..incomplete.len()) {
+        Some(dst) if string.len() + width <= ArrayString::<N, L>::capacity() => {
+            dst.copy_from_slice(incomplete);
+            partial.len = incomplete.len();
+            Ok(bytes.len())
+        }
+        _ => Ok(consumed + pushed),
+    }
+}
@@ -0,0 +1,93 @@
+//! Macros exported by this crate
+
+/// Creates an [`ArrayString`] (or [`CacheString`]) from a string literal, failing to compile if it's bigger than the capacity
+///
+/// The capacity can be inferred from context, be explicit or the full type can be provided. Everything happens at compile time, so it never panics.
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`CacheString`]: ./struct.CacheString.html
+///
+/// ```rust
+/// # use arraystring::{array_str, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let role: ArrayString<5> = array_str!("admin");
+/// assert_eq!(role.as_str(), "admin");
+///
+/// // Explicit capacity, when it can't be inferred
+/// let username = array_str!(20, "user");
+/// assert_eq!(username.as_str(), "user");
+///
+/// let path = array_str!(ArrayString<4096, u16>, "/usr/local/bin");
+/// assert_eq!(path.as_str(), "/usr/local/bin");
+///
+/// static GREETING: CacheString = array_str!(CacheString, "Hello");
+/// assert_eq!(GREETING.as_str(), "Hello");
+/// ```
+///
+/// ```compile_fail
+/// # use arraystring::{array_str, prelude::*};
+/// // Literal doesn't fit in 5 bytes
+/// let role: ArrayString<5> = array_str!("administrator");
+/// ```
+#[macro_export]
+macro_rules! array_str {
+    ($string:literal $(,)?) => {
+        const { $crate::ArrayString::from_static($string) }
+    };
+    ($capacity:literal, $string:literal $(,)?) => {
+        const { $crate::ArrayString::<$capacity>::from_static($string) }
+    };
+    ($string_type:ty, $string:literal $(,)?) => {
+        const { <$string_type>::from_static($string) }
+    };
+}
+
+/// Creates an [`ArrayString`] using interpolation of runtime expressions, like `format!`, returning [`OutOfBounds`] if it's bigger than the capacity
+///
+/// The capacity is inferred from context. It never allocates, so it's available in `no_std` builds.
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+///
+/// ```rust
+/// # use arraystring::{try_format, prelude::*};
+/// # fn main() -> Result<(), OutOfBounds> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let (level, message) = ("INFO", "connected");
+/// let line: ArrayString<23> = try_format!("[{level}] {message}")?;
+/// assert_eq!(line.as_str(), "[INFO] connected");
+///
+/// let out_of_bounds: Result<ArrayString<5>, _> = try_format!("[{level}] {message}");
+/// assert!(matches!(out_of_bounds, Err(OutOfBounds { .. })));
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_format {
+    ($($arg:tt)*) => {
+        $crate::ArrayString::try_from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+/// Creates an [`ArrayString`] using interpolation of runtime expressions, like `format!`, truncating it at the last char boundary that fits in the capacity
+///
+/// The capacity is inferred from context. It never allocates, so it's available in `no_std` builds.
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{format_truncate, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let (level, message) = ("INFO", "connected");
+/// let line: ArrayString<23> = format_truncate!("[{level}] {message}");
+/// assert_eq!(line.as_str(), "[INFO] connected");
+///
+/// let truncated: ArrayString<6> = format_truncate!("[{level}] {message}");
+/// assert_eq!(truncated.as_str(), "[INFO]");
+/// ```
+#[macro_export]
+macro_rules! format_truncate {
+    ($($arg:tt)*) => {
+        $crate::ArrayString::from_fmt_truncate(::core::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,120 @@
+//! Fixed capacity collection of [`ArrayString`]s
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::{arraystring::sealed::Length, prelude::*};
+use core::fmt::{self, Debug, Formatter};
+use core::{array, iter::Take, ops::Deref, slice};
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Fixed capacity collection of up to `K` [`ArrayString`]s, doesn't allocate.
+///
+/// Created through [`split_into`], derefs to a slice of the parts
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`split_into`]: ./struct.ArrayString.html#method.split_into
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let s = ArrayString::<23>::try_from_str("a=1;b=2")?;
+/// let parts = s.split_into::<3, 4>(';')?;
+/// assert_eq!(parts.len(), 2);
+/// assert_eq!(Parts::<3, 4>::capacity(), 4);
+/// for part in parts {
+///     assert_eq!(part.len(), 3);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Parts<const M: usize, const K: usize, L: Length = u8> {
+    /// Array of parts, only the first `len` are set
+    parts: [ArrayString<M, L>; K],
+    /// Number of parts
+    len: usize,
+}
+
+impl<const M: usize, const K: usize, L: Length> Parts<M, K, L> {
+    /// Creates new empty collection
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            parts: [ArrayString::new(); K],
+            len: 0,
+        }
+    }
+
+    /// Appends part, returning error if there are already `K` parts or if it's bigger than `M`
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub(crate) fn try_push(&mut self, part: &str) -> Result<(), OutOfBounds> {
+        let slot = self
+            .parts
+            .get_mut(self.len)
+            .ok_or(OutOfBounds::new(self.len + 1, K))?;
+        *slot = ArrayString::try_from_str(part)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Extracts a slice containing every part
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn as_slice(&self) -> &[ArrayString<M, L>] {
+        self.parts.get(..self.len).unwrap_or_default()
+    }
+
+    /// Returns maximum number of parts, defined at compile time, it will never change
+    #[inline]
+    pub const fn capacity() -> usize {
+        K
+    }
+}
+
+impl<const M: usize, const K: usize, L: Length> Deref for Parts<M, K, L> {
+    type Target = [ArrayString<M, L>];
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const M: usize, const K: usize, L: Length> Debug for Parts<M, K, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<const M: usize, const K: usize, L: Length> PartialEq for Parts<M, K, L> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice().eq(other.as_slice())
+    }
+}
+impl<const M: usize, const K: usize, L: Length> Eq for Parts<M, K, L> {}
+
+impl<const M: usize, const K: usize, L: Length> IntoIterator for Parts<M, K, L> {
+    type Item = ArrayString<M, L>;
+    type IntoIter = Take<array::IntoIter<ArrayString<M, L>, K>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter().take(self.len)
+    }
+}
+
+impl<'a, const M: usize, const K: usize, L: Length> IntoIterator for &'a Parts<M, K, L> {
+    type Item = &'a ArrayString<M, L>;
+    type IntoIter = slice::Iter<'a, ArrayString<M, L>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
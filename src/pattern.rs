@@ -0,0 +1,230 @@
+//! Patterns to search for in an [`ArrayString`]
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::ops::Range;
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Something to search for in a string, a stable stand-in for `core::str::pattern::Pattern`
+///
+/// Implemented for `char`, `&str`, `&String`, `[char; K]`, `&[char]` and `FnMut(char) -> bool` closures. It's sealed, so it can't be implemented outside of this crate
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<23>::try_from_str("a-b_c d")?;
+/// s.replace_in_place('-', "+")?;
+/// s.replace_in_place("_", "+")?;
+/// s.replace_in_place(char::is_whitespace, "+")?;
+/// assert_eq!(s, "a+b+c+d");
+/// s.replace_in_place(['a', 'd'], "")?;
+/// assert_eq!(s, "+b+c+");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Pattern: sealed::Sealed {
+    /// Returns the byte range of the first match in `haystack`
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Returns the byte range of the last match in `haystack`
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Returns the length of the match at the start of `haystack`, if it starts with one
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the length of the match at the end of `haystack`, if it ends with one
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize>;
+}
+
+impl Pattern for char {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Find {self:?} in {haystack}");
+        let start = haystack.find(*self)?;
+        Some(start..start + self.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Rfind {self:?} in {haystack}");
+        let start = haystack.rfind(*self)?;
+        Some(start..start + self.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then(|| self.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then(|| self.len_utf8())
+    }
+}
+
+impl Pattern for &str {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Find {self:?} in {haystack}");
+        let start = haystack.find(*self)?;
+        Some(start..start + self.len())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Rfind {self:?} in {haystack}");
+        let start = haystack.rfind(*self)?;
+        Some(start..start + self.len())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then_some(self.len())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then_some(self.len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Pattern for &String {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().find_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().rfind_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().prefix_len_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().suffix_len_in(haystack)
+    }
+}
+
+impl<const K: usize> Pattern for [char; K] {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_slice().find_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_slice().rfind_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().prefix_len_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().suffix_len_in(haystack)
+    }
+}
+
+impl Pattern for &[char] {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Find any of {self:?} in {haystack}");
+        (|ch| self.contains(&ch)).find_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        trace!("Rfind any of {self:?} in {haystack}");
+        (|ch| self.contains(&ch)).rfind_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        (|ch| self.contains(&ch)).prefix_len_in(haystack)
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        (|ch| self.contains(&ch)).suffix_len_in(haystack)
+    }
+}
+
+impl<F: FnMut(char) -> bool> Pattern for F {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        let (start, ch) = haystack.char_indices().find(|(_, ch)| self(*ch))?;
+        Some(start..start + ch.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        let (start, ch) = haystack.char_indices().rfind(|(_, ch)| self(*ch))?;
+        Some(start..start + ch.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        let ch = haystack.chars().next().filter(|ch| self(*ch))?;
+        Some(ch.len_utf8())
+    }
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+        let ch = haystack.chars().next_back().filter(|ch| self(*ch))?;
+        Some(ch.len_utf8())
+    }
+}
+
+/// Seals [`Pattern`], so it can't be implemented outside of this crate
+mod sealed {
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
+
+    pub trait Sealed {}
+
+    impl Sealed for char {}
+    impl Sealed for &str {}
+    #[cfg(feature = "alloc")]
+    impl Sealed for &String {}
+    impl<const K: usize> Sealed for [char; K] {}
+    impl Sealed for &[char] {}
+    impl<F: FnMut(char) -> bool> Sealed for F {}
+}
@@ -0,0 +1,82 @@
+//! `io::Read` adapter over the bytes of an [`ArrayString`]
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use std::io::{self, BufRead, Read};
+
+/// Reader that yields the bytes of an [`ArrayString`], implementing `io::Read` and `io::BufRead`
+///
+/// Created through [`reader`]
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`reader`]: ./struct.ArrayString.html#method.reader
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # use std::io::{self, Read};
+/// # fn main() -> io::Result<()> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let s = ArrayString::<23>::try_from_str("My String").unwrap();
+/// let mut copy = ArrayString::<23>::new();
+/// let copied = io::copy(&mut s.reader(), &mut copy)?;
+/// assert_eq!(copied, 9);
+/// assert_eq!(copy, s);
+///
+/// let mut buf = [0; 2];
+/// let mut reader = s.reader();
+/// reader.read_exact(&mut buf)?;
+/// assert_eq!(&buf, b"My");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reader<'a> {
+    /// Bytes that haven't been read yet
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Creates reader over the string's bytes
+    #[inline]
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the part of the string that hasn't been read yet
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use std::io::{self, Read};
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("My String").unwrap();
+    /// let mut reader = s.reader();
+    /// reader.read_exact(&mut [0; 3])?;
+    /// assert_eq!(reader.remaining(), b"String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl Read for Reader<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.bytes.read(buf)
+    }
+}
+
+impl BufRead for Reader<'_> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.bytes)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.bytes.consume(amt);
+    }
+}
@@ -0,0 +1,80 @@
+//! Truncating `fmt::Write` adapter for [`ArrayString`]
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::{arraystring::sealed::Length, prelude::*};
+use core::fmt::{self, Debug, Formatter, Write};
+
+/// A `fmt::Write` adapter for [`ArrayString`] that truncates instead of failing.
+///
+/// Fills the string up to the last char boundary that fits, every write after the first overflow is dropped, so the output is always a prefix of the formatted text. It never returns an error and counts how many bytes were dropped.
+///
+/// Created through [`truncating`]
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`truncating`]: ./struct.ArrayString.html#method.truncating
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # use core::fmt::Write;
+/// # fn main() -> Result<(), core::fmt::Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<10>::new();
+/// let mut writer = s.truncating();
+/// write!(writer, "{}:{}", "localhost", 8080)?;
+/// assert!(writer.is_truncated());
+/// assert_eq!(writer.dropped(), 4);
+/// assert_eq!(s.as_str(), "localhost:");
+/// # Ok(())
+/// # }
+/// ```
+pub struct Truncating<'a, const N: usize, L: Length = u8> {
+    /// String being written to
+    string: &'a mut ArrayString<N, L>,
+    /// Bytes that didn't fit in the string
+    dropped: usize,
+}
+
+impl<'a, const N: usize, L: Length> Truncating<'a, N, L> {
+    /// Creates truncating writer that appends to `string`
+    #[inline]
+    pub fn new(string: &'a mut ArrayString<N, L>) -> Self {
+        Self { string, dropped: 0 }
+    }
+
+    /// Returns how many bytes didn't fit in the string and were dropped
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Returns `true` if any byte was dropped
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.dropped > 0
+    }
+}
+
+impl<const N: usize, L: Length> Debug for Truncating<'_, N, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Truncating")
+            .field("string", &self.string)
+            .field("dropped", &self.dropped)
+            .finish()
+    }
+}
+
+impl<const N: usize, L: Length> Write for Truncating<'_, N, L> {
+    #[inline]
+    fn write_str(&mut self, slice: &str) -> fmt::Result {
+        if self.dropped > 0 {
+            self.dropped += slice.len();
+            return Ok(());
+        }
+        let len = self.string.len();
+        self.string.push_str_truncate(slice);
+        self.dropped = slice.len() - (self.string.len() - len);
+        Ok(())
+    }
+}
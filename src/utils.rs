@@ -1,6 +1,7 @@
 //! Misc functions to improve readability
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+use crate::{arraystring::sealed::Length, prelude::*};
+use core::fmt::{self, Write};
 #[cfg(feature = "logs")]
 use log::trace;
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
@@ -15,21 +16,33 @@ pub(crate) trait IntoLossy<T>: Sized {
 #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
 pub(crate) fn is_inside_boundary(size: usize, limit: usize) -> Result<(), OutOfBounds> {
     trace!("Out of bounds: ensures {} <= {}", size, limit);
-    (size <= limit).then_some(()).ok_or(OutOfBounds)
+    (size <= limit)
+        .then_some(())
+        .ok_or(OutOfBounds::new(size, limit))
+}
+
+/// Returns error if range starts after it ends
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn is_valid_range(start: usize, end: usize) -> Result<(), InvalidRange> {
+    trace!("Invalid range: ensures {} <= {}", start, end);
+    (start <= end)
+        .then_some(())
+        .ok_or(InvalidRange::new(start, end))
 }
 
 /// Returns error if index is not at a valid utf-8 char boundary
 #[inline]
 #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-pub(crate) fn is_char_boundary<const N: usize>(s: &ArrayString<N>, idx: usize) -> Result<(), Utf8>
-where
-    ArrayString<N>: ValidCapacity,
-{
+pub(crate) fn is_char_boundary<const N: usize, L: Length>(
+    s: &ArrayString<N, L>,
+    idx: usize,
+) -> Result<(), NotCharBoundary> {
     trace!("Is char boundary: {} at {}", s.as_str(), idx);
     if s.as_str().is_char_boundary(idx) {
         return Ok(());
     }
-    Err(Utf8)
+    Err(NotCharBoundary::new(idx))
 }
 
 /// Truncates string to specified size (ignoring last bytes if they form a partial `char`)
@@ -60,12 +73,63 @@ pub(crate) fn truncate_str(slice: &str, mut size: usize) -> &str {
     }
 }
 
-impl IntoLossy<u8> for usize {
-    #[allow(clippy::cast_possible_truncation)]
+/// Number of bytes in the utf-8 sequence started by `byte`, 0 if it can't start one
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn utf8_char_width(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
+}
+
+/// Returns true if byte continues a multi-byte utf-8 sequence
+#[cfg(feature = "std")]
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn is_utf8_continuation(byte: u8) -> bool {
+    byte & 0xC0 == 0x80
+}
+
+/// `fmt::Write` adapter that records if the string overflowed, so it can be told apart from formatting errors
+pub(crate) struct OverflowCheck<'a, const N: usize, L: Length> {
+    string: &'a mut ArrayString<N, L>,
+    /// Total length formatted, even after it stops fitting
+    pub(crate) required: usize,
+    pub(crate) overflowed: bool,
+}
+
+impl<'a, const N: usize, L: Length> OverflowCheck<'a, N, L> {
+    #[inline]
+    pub(crate) fn new(string: &'a mut ArrayString<N, L>) -> Self {
+        Self {
+            string,
+            required: 0,
+            overflowed: false,
+        }
+    }
+}
+
+impl<const N: usize, L: Length> Write for OverflowCheck<'_, N, L> {
+    #[inline]
+    fn write_str(&mut self, slice: &str) -> fmt::Result {
+        // Keeps formatting after overflowing only to count the required length
+        self.required = self.required.saturating_add(slice.len());
+        if !self.overflowed && self.string.try_push_str(slice).is_err() {
+            self.overflowed = true;
+        }
+        Ok(())
+    }
+}
+
+impl<L: Length> IntoLossy<L> for usize {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn into_lossy(self) -> u8 {
-        self as u8
+    fn into_lossy(self) -> L {
+        L::from_usize_lossy(self)
     }
 }
 
@@ -0,0 +1,623 @@
+//! [`ArrayString`] wrapper that upholds a [`Validator`]'s invariant
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+//! [`Validator`]: ./validator/trait.Validator.html
+
+use crate::{arraystring::sealed::Length, prelude::*, validator::Validator, Error};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::{borrow::Borrow, cmp::Ordering, hash::Hash, hash::Hasher, marker::PhantomData};
+use core::{ops::Deref, ops::RangeBounds, str::FromStr};
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// [`ArrayString`] that always upholds the invariant of the [`Validator`] `V`
+///
+/// The invariant is checked on construction and after every mutation, a mutation that would break it is rolled back and returns [`Invalid`]. Read-only methods are reachable through `Deref<Target = ArrayString<N, L>>`.
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`Validator`]: ./validator/trait.Validator.html
+/// [`Invalid`]: ./error/struct.Invalid.html
+///
+/// ```rust
+/// # use arraystring::{Error, validator::{Alphanumeric, NonEmpty}, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type Username = ValidatedArrayString<20, (NonEmpty, Alphanumeric)>;
+///
+/// let mut username = Username::try_from_str("admin")?;
+/// assert!(matches!(username.try_push('!'), Err(Error::Invalid(_))));
+/// assert!(matches!(username.clear(), Err(_)));
+/// assert_eq!(username.as_str(), "admin");
+///
+/// username.try_push_str("42")?;
+/// assert_eq!(username.len(), 7);
+/// assert_eq!(username, "admin42");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(
+    feature = "diesel-traits",
+    derive(diesel::AsExpression, diesel::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel-traits", diesel(sql_type = diesel::sql_types::Text))]
+pub struct ValidatedArrayString<const N: usize, V, L: Length = u8> {
+    /// String that upholds the invariant
+    pub(crate) string: ArrayString<N, L>,
+    /// Invariant checked on every mutation
+    validator: PhantomData<fn() -> V>,
+}
+
+impl<const N: usize, V: Validator, L: Length> ValidatedArrayString<N, V, L> {
+    /// Fails compilation if no string that fits in the capacity could uphold the invariant
+    const MIN_LEN_FITS: () = assert!(
+        V::MIN_LEN <= N,
+        "ValidatedArrayString capacity is smaller than its validator's minimum length"
+    );
+
+    /// Creates new string from string slice if it fits in [`capacity`] and upholds the invariant, otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ValidatedArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let code = ValidatedArrayString::<2, Ascii>::try_from_str("BR")?;
+    /// assert_eq!(code.as_str(), "BR");
+    ///
+    /// let out_of_bounds = ValidatedArrayString::<2, Ascii>::try_from_str("BRA");
+    /// assert!(matches!(out_of_bounds, Err(Error::OutOfBounds(_))));
+    /// let invalid = ValidatedArrayString::<2, Ascii>::try_from_str("Ç");
+    /// assert!(matches!(invalid, Err(Error::Invalid(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(Self::try_from_array_string(ArrayString::try_from_str(
+            string,
+        )?)?)
+    }
+
+    /// Wraps the string if it upholds the invariant, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{validator::NonEmpty, prelude::*};
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = ArrayString::<23>::from_str_truncate("abc");
+    /// let validated = ValidatedArrayString::<23, NonEmpty>::try_from_array_string(string).unwrap();
+    /// assert_eq!(validated.as_str(), "abc");
+    ///
+    /// let empty = ArrayString::<23>::new();
+    /// assert!(ValidatedArrayString::<23, NonEmpty>::try_from_array_string(empty).is_err());
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_array_string(string: ArrayString<N, L>) -> Result<Self, Invalid> {
+        trace!("Validate: {string:?}");
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MIN_LEN_FITS;
+        V::validate(string.as_str())?;
+        Ok(Self {
+            string,
+            validator: PhantomData,
+        })
+    }
+
+    /// Unwraps the inner string, dropping the invariant
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::NonEmpty, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let validated = ValidatedArrayString::<23, NonEmpty>::try_from_str("abc")?;
+    /// let mut string = validated.into_inner();
+    /// string.clear();
+    /// assert!(string.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn into_inner(self) -> ArrayString<N, L> {
+        self.string
+    }
+
+    /// Returns maximum string capacity, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::{validator::NonEmpty, prelude::*};
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ValidatedArrayString::<32, NonEmpty>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// Applies `f` to a copy of the string and keeps the result only if it upholds the invariant
+    ///
+    /// Escape hatch for mutations that don't have a validated counterpart
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Alphanumeric, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Alphanumeric>::try_from_str("abc")?;
+    /// s.try_modify(|s| s.make_ascii_uppercase())?;
+    /// assert_eq!(s.as_str(), "ABC");
+    ///
+    /// assert!(s.try_modify(|s| s.push_str_truncate(" DEF")).is_err());
+    /// assert_eq!(s.as_str(), "ABC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_modify<T>(
+        &mut self,
+        f: impl FnOnce(&mut ArrayString<N, L>) -> T,
+    ) -> Result<T, Invalid> {
+        let mut string = self.string;
+        let value = f(&mut string);
+        trace!("Validate: {string:?}");
+        V::validate(string.as_str())?;
+        self.string = string;
+        Ok(value)
+    }
+
+    /// Same as [`try_modify`], but `f` may fail too
+    ///
+    /// [`try_modify`]: ./struct.ValidatedArrayString.html#method.try_modify
+    #[inline]
+    fn try_modify_with<T, E>(
+        &mut self,
+        f: impl FnOnce(&mut ArrayString<N, L>) -> Result<T, E>,
+    ) -> Result<T, Error>
+    where
+        Error: From<E>,
+    {
+        Ok(self.try_modify(f)??)
+    }
+
+    /// Pushes string slice to the end of the string if it fits and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<8, Ascii>::try_from_str("ab")?;
+    /// s.try_push_str("cd")?;
+    /// assert_eq!(s.as_str(), "abcd");
+    ///
+    /// assert!(matches!(s.try_push_str("ç"), Err(Error::Invalid(_))));
+    /// assert!(matches!(s.try_push_str("efghi"), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "abcd");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), Error> {
+        self.try_modify_with(|s| s.try_push_str(string))
+    }
+
+    /// Pushes character to the end of the string if it fits and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<3, Ascii>::try_from_str("a")?;
+    /// assert!(matches!(s.try_push('ç'), Err(Error::Invalid(_))));
+    /// s.try_push('b')?;
+    /// s.try_push('c')?;
+    /// assert!(matches!(s.try_push('d'), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "abc");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), Error> {
+        self.try_modify_with(|s| s.try_push(ch))
+    }
+
+    /// Truncates string to the specified size if it's at a char boundary and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::MinLen, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, MinLen<2>>::try_from_str("a🤔b")?;
+    /// assert!(matches!(s.truncate(2), Err(Error::NotCharBoundary(_))));
+    /// assert!(matches!(s.truncate(1), Err(Error::Invalid(_))));
+    /// s.truncate(5)?;
+    /// assert_eq!(s.as_str(), "a🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate(&mut self, size: usize) -> Result<(), Error> {
+        self.try_modify_with(|s| s.truncate(size))
+    }
+
+    /// Removes last character from the string if the result upholds the invariant, otherwise returns an error
+    ///
+    /// Returns `Ok(None)` if the string is empty and the invariant allows it
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::NonEmpty, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, NonEmpty>::try_from_str("A🤔")?;
+    /// assert_eq!(s.pop()?, Some('🤔'));
+    /// assert!(s.pop().is_err());
+    /// assert_eq!(s.as_str(), "A");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Result<Option<char>, Invalid> {
+        self.try_modify(|s| s.pop())
+    }
+
+    /// Removes whitespaces from the beggining and end of the string if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::NonEmpty, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, NonEmpty>::try_from_str("  name  ")?;
+    /// s.trim()?;
+    /// assert_eq!(s.as_str(), "name");
+    ///
+    /// let mut s = ValidatedArrayString::<23, NonEmpty>::try_from_str("    ")?;
+    /// assert!(s.trim().is_err());
+    /// assert_eq!(s.as_str(), "    ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn trim(&mut self) -> Result<(), Invalid> {
+        self.try_modify(|s| s.trim())
+    }
+
+    /// Removes specified char from the string if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::MinLen, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, MinLen<2>>::try_from_str("ABC")?;
+    /// assert_eq!(s.remove(1)?, 'B');
+    /// assert!(matches!(s.remove(0), Err(Error::Invalid(_))));
+    /// assert!(matches!(s.remove(2), Err(Error::OutOfBounds(_))));
+    /// assert_eq!(s.as_str(), "AC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
+        self.try_modify_with(|s| s.remove(idx))
+    }
+
+    /// Retains only the characters that satisfy the predicate if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::NonEmpty, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, NonEmpty>::try_from_str("ABCDE")?;
+    /// s.retain(|c| c != 'B')?;
+    /// assert_eq!(s.as_str(), "ACDE");
+    ///
+    /// assert!(s.retain(|c| c == 'B').is_err());
+    /// assert_eq!(s.as_str(), "ACDE");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn retain(&mut self, f: impl FnMut(char) -> bool) -> Result<(), Invalid> {
+        self.try_modify(|s| s.retain(f))
+    }
+
+    /// Inserts character at specified index if it fits and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Ascii>::try_from_str("AC")?;
+    /// s.try_insert(1, 'B')?;
+    /// assert!(matches!(s.try_insert(0, 'Á'), Err(Error::Invalid(_))));
+    /// assert_eq!(s.as_str(), "ABC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), Error> {
+        self.try_modify_with(|s| s.try_insert(idx, ch))
+    }
+
+    /// Inserts string slice at specified index if it fits and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Alphanumeric, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Alphanumeric>::try_from_str("AD")?;
+    /// s.try_insert_str(1, "BC")?;
+    /// assert!(matches!(s.try_insert_str(1, " "), Err(Error::Invalid(_))));
+    /// assert_eq!(s.as_str(), "ABCD");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_insert_str(&mut self, idx: usize, string: impl AsRef<str>) -> Result<(), Error> {
+        self.try_modify_with(|s| s.try_insert_str(idx, string))
+    }
+
+    /// Empties the string if the invariant allows it, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::{Ascii, NonEmpty}, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Ascii>::try_from_str("abc")?;
+    /// s.clear()?;
+    /// assert!(s.is_empty());
+    ///
+    /// let mut s = ValidatedArrayString::<23, NonEmpty>::try_from_str("abc")?;
+    /// assert!(s.clear().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn clear(&mut self) -> Result<(), Invalid> {
+        self.try_modify(|s| s.clear())
+    }
+
+    /// Removes the specified range of the string if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::MinLen, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, MinLen<3>>::try_from_str("ABCDE")?;
+    /// let drained = s.drain(..2)?;
+    /// assert_eq!(drained.as_str(), "AB");
+    /// assert!(matches!(s.drain(1..), Err(Error::Invalid(_))));
+    /// assert_eq!(s.as_str(), "CDE");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N, L>, Error> {
+        self.try_modify_with(|s| s.drain(range))
+    }
+
+    /// Replaces the specified range of the string with a string slice if it fits and the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Ascii>::try_from_str("ABCDE")?;
+    /// s.replace_range(1..3, "-")?;
+    /// assert_eq!(s.as_str(), "A-DE");
+    /// assert!(matches!(s.replace_range(1..2, "ç"), Err(Error::Invalid(_))));
+    /// assert!(matches!(s.replace_range(3..1, ""), Err(Error::InvalidRange(_))));
+    /// assert_eq!(s.as_str(), "A-DE");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn replace_range(
+        &mut self,
+        r: impl RangeBounds<usize>,
+        with: impl AsRef<str>,
+    ) -> Result<(), Error> {
+        self.try_modify_with(|s| s.replace_range(r, with))
+    }
+
+    /// Converts the string to its ASCII upper case equivalent in-place if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Ascii>::try_from_str("br")?;
+    /// s.make_ascii_uppercase()?;
+    /// assert_eq!(s.as_str(), "BR");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn make_ascii_uppercase(&mut self) -> Result<(), Invalid> {
+        self.try_modify(|s| s.make_ascii_uppercase())
+    }
+
+    /// Converts the string to its ASCII lower case equivalent in-place if the result upholds the invariant, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, validator::Ascii, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ValidatedArrayString::<23, Ascii>::try_from_str("BR")?;
+    /// s.make_ascii_lowercase()?;
+    /// assert_eq!(s.as_str(), "br");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn make_ascii_lowercase(&mut self) -> Result<(), Invalid> {
+        self.try_modify(|s| s.make_ascii_lowercase())
+    }
+}
+
+impl<const N: usize, V, L: Length> Copy for ValidatedArrayString<N, V, L> {}
+
+impl<const N: usize, V, L: Length> Clone for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, V, L: Length> Deref for ValidatedArrayString<N, V, L> {
+    type Target = ArrayString<N, L>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.string
+    }
+}
+
+impl<const N: usize, V, L: Length> AsRef<str> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.string.as_str()
+    }
+}
+
+impl<const N: usize, V, L: Length> AsRef<[u8]> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+}
+
+impl<const N: usize, V, L: Length> AsRef<ArrayString<N, L>> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn as_ref(&self) -> &ArrayString<N, L> {
+        &self.string
+    }
+}
+
+impl<const N: usize, V, L: Length> Borrow<str> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.string.as_str()
+    }
+}
+
+impl<const N: usize, V, L: Length> From<ValidatedArrayString<N, V, L>> for ArrayString<N, L> {
+    #[inline]
+    fn from(string: ValidatedArrayString<N, V, L>) -> Self {
+        string.string
+    }
+}
+
+impl<const N: usize, V: Validator, L: Length> TryFrom<ArrayString<N, L>>
+    for ValidatedArrayString<N, V, L>
+{
+    type Error = Invalid;
+
+    #[inline]
+    fn try_from(string: ArrayString<N, L>) -> Result<Self, Self::Error> {
+        Self::try_from_array_string(string)
+    }
+}
+
+impl<'a, const N: usize, V: Validator, L: Length> TryFrom<&'a str>
+    for ValidatedArrayString<N, V, L>
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, V: Validator, L: Length> FromStr for ValidatedArrayString<N, V, L> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, V, L: Length> Debug for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ValidatedArrayString")
+            .field(&self.string)
+            .finish()
+    }
+}
+
+impl<const N: usize, V, L: Length> Display for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.string, f)
+    }
+}
+
+impl<const N: usize, V, L: Length> PartialEq for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.string.eq(&other.string)
+    }
+}
+
+impl<const N: usize, V, L: Length> Eq for ValidatedArrayString<N, V, L> {}
+
+impl<const N: usize, V, L: Length> PartialEq<str> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.string.as_str().eq(other)
+    }
+}
+
+impl<const N: usize, V, L: Length> PartialEq<&str> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.string.as_str().eq(*other)
+    }
+}
+
+impl<const N: usize, V, L: Length> PartialEq<ArrayString<N, L>> for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn eq(&self, other: &ArrayString<N, L>) -> bool {
+        self.string.eq(other)
+    }
+}
+
+impl<const N: usize, V, L: Length> Ord for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.string.as_str().cmp(other.string.as_str())
+    }
+}
+
+impl<const N: usize, V, L: Length> PartialOrd for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, V, L: Length> Hash for ValidatedArrayString<N, V, L> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.string.hash(hasher)
+    }
+}
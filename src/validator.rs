@@ -0,0 +1,170 @@
+//! Invariants checked by [`ValidatedArrayString`] on construction and on every mutation
+//!
+//! [`ValidatedArrayString`]: ../struct.ValidatedArrayString.html
+
+use crate::error::Invalid;
+
+/// `const` version of `Ord::max`
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Invariant a [`ValidatedArrayString`] must always uphold
+///
+/// Validators are types only used as a generic parameter, they are never instantiated. A tuple of validators requires all of them.
+///
+/// [`ValidatedArrayString`]: ../struct.ValidatedArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, error::Invalid, validator::Validator, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// struct Sku;
+///
+/// impl Validator for Sku {
+///     fn validate(string: &str) -> Result<(), Invalid> {
+///         match string.split_once('-') {
+///             Some((kind, id)) if !kind.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) => Ok(()),
+///             _ => Err(Invalid::new("not a sku")),
+///         }
+///     }
+/// }
+///
+/// let mut sku = ValidatedArrayString::<16, Sku>::try_from_str("BOOK-1")?;
+/// sku.try_push('2')?;
+/// assert_eq!(sku.as_str(), "BOOK-12");
+/// assert!(sku.try_push('A').is_err());
+/// assert_eq!(sku.as_str(), "BOOK-12");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Validator {
+    /// Minimum length in bytes a string needs to uphold the invariant, a capacity smaller than it fails to compile
+    const MIN_LEN: usize = 0;
+
+    /// Checks if `string` upholds the invariant
+    fn validate(string: &str) -> Result<(), Invalid>;
+}
+
+/// Accepts every string
+impl Validator for () {
+    #[inline]
+    fn validate(_: &str) -> Result<(), Invalid> {
+        Ok(())
+    }
+}
+
+impl<A: Validator, B: Validator> Validator for (A, B) {
+    const MIN_LEN: usize = max(A::MIN_LEN, B::MIN_LEN);
+
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        A::validate(string)?;
+        B::validate(string)
+    }
+}
+
+impl<A: Validator, B: Validator, C: Validator> Validator for (A, B, C) {
+    const MIN_LEN: usize = max(max(A::MIN_LEN, B::MIN_LEN), C::MIN_LEN);
+
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        A::validate(string)?;
+        B::validate(string)?;
+        C::validate(string)
+    }
+}
+
+/// Only accepts ASCII strings
+///
+/// ```rust
+/// # use arraystring::{validator::Ascii, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// assert!(ValidatedArrayString::<23, Ascii>::try_from_str("BR-RJ").is_ok());
+/// assert!(ValidatedArrayString::<23, Ascii>::try_from_str("São Paulo").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ascii;
+
+impl Validator for Ascii {
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        if string.is_ascii() {
+            Ok(())
+        } else {
+            Err(Invalid::new("not ascii"))
+        }
+    }
+}
+
+/// Only accepts strings made of alphanumeric chars (as in `char::is_alphanumeric`)
+///
+/// ```rust
+/// # use arraystring::{validator::Alphanumeric, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// assert!(ValidatedArrayString::<23, Alphanumeric>::try_from_str("user42").is_ok());
+/// assert!(ValidatedArrayString::<23, Alphanumeric>::try_from_str("user 42").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Alphanumeric;
+
+impl Validator for Alphanumeric {
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        if string.chars().all(char::is_alphanumeric) {
+            Ok(())
+        } else {
+            Err(Invalid::new("not alphanumeric"))
+        }
+    }
+}
+
+/// Only accepts strings with at least one char
+///
+/// ```rust
+/// # use arraystring::{validator::NonEmpty, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// assert!(ValidatedArrayString::<23, NonEmpty>::try_from_str("a").is_ok());
+/// assert!(ValidatedArrayString::<23, NonEmpty>::try_from_str("").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        if string.is_empty() {
+            Err(Invalid::new("empty"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Only accepts strings with at least `MIN` bytes
+///
+/// ```rust
+/// # use arraystring::{validator::MinLen, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// assert!(ValidatedArrayString::<23, MinLen<8>>::try_from_str("hunter22").is_ok());
+/// assert!(ValidatedArrayString::<23, MinLen<8>>::try_from_str("hunter2").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MinLen<const MIN: usize>;
+
+impl<const MIN: usize> Validator for MinLen<MIN> {
+    const MIN_LEN: usize = MIN;
+
+    #[inline]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        if string.len() < MIN {
+            Err(Invalid::new("shorter than minimum length"))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! `io::Write` adapter that appends to an [`ArrayString`], keeping chars split between writes
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::implementations::write_utf8;
+use crate::utils::{is_utf8_continuation, utf8_char_width};
+use crate::{arraystring::sealed::Length, prelude::*};
+use core::str;
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+use std::io::{self, Write};
+
+/// Writer that appends utf-8 bytes to an [`ArrayString`], implementing `io::Write`
+///
+/// Unlike writing to the string directly, a char split between writes is kept until the next write completes it, so bytes can be split anywhere. A write that can't continue it returns `ErrorKind::InvalidData`.
+///
+/// Created through [`writer`]
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`writer`]: ./struct.ArrayString.html#method.writer
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # use std::io::{self, Write};
+/// # fn main() -> io::Result<()> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<8>::new();
+/// let thinking = "🤔".as_bytes();
+/// let mut writer = s.writer();
+/// writer.write_all(&thinking[..1])?;
+/// assert_eq!(writer.pending(), &thinking[..1]);
+/// writer.write_all(&thinking[1..])?;
+/// assert_eq!(s.as_str(), "🤔");
+///
+/// let mut writer = s.writer();
+/// assert_eq!(writer.write(b"\xC3")?, 1);
+/// assert_eq!(writer.write(b"a").unwrap_err().kind(), io::ErrorKind::InvalidData);
+/// assert_eq!(s.as_str(), "🤔");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Writer<'a, const N: usize, L: Length = u8> {
+    /// String the bytes are appended to
+    string: &'a mut ArrayString<N, L>,
+    /// Start of a char cut off at the end of the last write
+    pending: [u8; 4],
+    /// Number of bytes in `pending`
+    pending_len: usize,
+}
+
+impl<'a, const N: usize, L: Length> Writer<'a, N, L> {
+    /// Creates writer that appends to the string
+    #[inline]
+    pub(crate) fn new(string: &'a mut ArrayString<N, L>) -> Self {
+        Self {
+            string,
+            pending: [0; 4],
+            pending_len: 0,
+        }
+    }
+
+    /// Returns the start of a char cut off at the end of the last write, that hasn't been appended yet
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # use std::io::{self, Write};
+    /// # fn main() -> io::Result<()> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::new();
+    /// let mut writer = s.writer();
+    /// writer.write_all(b"caf\xC3")?;
+    /// assert_eq!(writer.pending(), b"\xC3");
+    /// writer.write_all(b"\xA9")?;
+    /// assert!(writer.pending().is_empty());
+    /// assert_eq!(s.as_str(), "café");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pending(&self) -> &[u8] {
+        self.pending.get(..self.pending_len).unwrap_or_default()
+    }
+
+    /// Appends continuation bytes from `buf` to the pending char, returns how many were used
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn complete_pending(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let width = utf8_char_width(self.pending[0]);
+        let missing = width.saturating_sub(self.pending_len);
+        let used = buf.iter().take(missing);
+        let used = used.take_while(|b| is_utf8_continuation(**b)).count();
+        if used < missing && used < buf.len() {
+            // Interrupted by a byte that can't continue the char
+            self.pending_len = 0;
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        let (Some(continuation), Some(bytes)) = (
+            self.pending
+                .get_mut(self.pending_len..self.pending_len + used),
+            buf.get(..used),
+        ) else {
+            return Err(io::ErrorKind::InvalidData.into());
+        };
+        continuation.copy_from_slice(bytes);
+        self.pending_len += used;
+        if self.pending_len < width {
+            return Ok(used);
+        }
+        self.pending_len = 0;
+        match self.pending.get(..width).map(str::from_utf8) {
+            // Only buffered if the whole char fits, and the string can't change while it's borrowed
+            Some(Ok(ch)) => match self.string.try_push_str(ch) {
+                Ok(()) => Ok(used),
+                Err(_) => Err(io::ErrorKind::WriteZero.into()),
+            },
+            _ => Err(io::ErrorKind::InvalidData.into()),
+        }
+    }
+}
+
+impl<const N: usize, L: Length> Write for Writer<'_, N, L> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        trace!("Writer write: {buf:?} to {}", self.string);
+        if self.pending_len > 0 {
+            return self.complete_pending(buf);
+        }
+        let (written, incomplete) = write_utf8(self.string, buf)?;
+        if let Some(pending) = self.pending.get_mut(..incomplete.len()) {
+            pending.copy_from_slice(incomplete);
+            self.pending_len = incomplete.len();
+        }
+        Ok(written + self.pending_len)
+    }
+
+    /// Returns `ErrorKind::InvalidData` if a char split between writes is still incomplete, keeping it
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn flush(&mut self) -> io::Result<()> {
+        match self.pending_len {
+            0 => Ok(()),
+            _ => Err(io::ErrorKind::InvalidData.into()),
+        }
+    }
+}
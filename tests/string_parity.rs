@@ -1,8 +1,6 @@
 use arraystring::prelude::*;
-use std::panic::{catch_unwind, AssertUnwindSafe, RefUnwindSafe};
 use std::fmt::Debug;
-
-type TestString = ArrayString<255>;
+use std::panic::{catch_unwind, AssertUnwindSafe, RefUnwindSafe};
 
 fn unwind<R, F>(func: F) -> Result<R, ()>
 where
@@ -22,398 +20,1349 @@ static STRINGS: [&str; 8] = [
     "        899saH(8hadhaiuhsidnkandu",
 ];
 
-#[test]
-fn try_from_str() {
-    assert(String::from, TestString::try_from_str);
-}
+macro_rules! parity_tests {
+    ($($module:ident: $string:ty),* $(,)*) => {
+        $(
+        mod $module {
+            use super::*;
 
-#[test]
-fn from_str_truncate() {
-    assert(String::from, TestString::from_str_truncate);
-}
+            type TestString = $string;
 
-#[test]
-fn try_from_chars() {
-    assert(
-        |s| String::from_iter(s.chars()),
-        |s| TestString::try_from_chars(s.chars()),
-    );
-}
+            impl Normalize<Result<String, ()>> for TestString {
+                fn normalize(&self) -> Result<String, ()> {
+                    Ok(self.as_str().to_owned())
+                }
+            }
 
-#[test]
-fn from_chars() {
-    assert(
-        |s| String::from_iter(s.chars()),
-        |s| TestString::from_chars_truncate(s.chars()),
-    );
-}
+            #[test]
+            fn try_from_str() {
+                assert(String::from, TestString::try_from_str);
+            }
 
-#[test]
-fn try_from_iter() {
-    assert(
-        |s| String::from_iter(vec![s]),
-        |s| TestString::try_from_iterator(vec![s]),
-    );
-}
+            #[test]
+            fn from_str_truncate() {
+                assert(String::from, TestString::from_str_truncate);
+            }
 
-#[test]
-fn from_iter() {
-    assert(
-        |s| String::from_iter(vec![s]),
-        |s| TestString::from_iterator_truncate(vec![s]),
-    );
-}
+            #[test]
+            fn from_str_unchecked() {
+                assert(String::from, |s| unsafe { TestString::from_str_unchecked(s) });
+            }
 
-#[test]
-fn try_from_utf16() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16(&utf16(s)),
-        |s| TestString::try_from_utf16(&utf16(s)),
-    );
-}
+            #[test]
+            fn try_from_chars() {
+                assert(
+                    |s| String::from_iter(s.chars()),
+                    |s| TestString::try_from_chars(s.chars()),
+                );
+            }
 
-#[test]
-fn from_utf16() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16(&utf16(s)),
-        |s| TestString::from_utf16_truncate(&utf16(s)),
-    );
-}
+            #[test]
+            fn from_chars() {
+                assert(
+                    |s| String::from_iter(s.chars()),
+                    |s| TestString::from_chars_truncate(s.chars()),
+                );
+            }
 
-#[test]
-fn from_utf16_lossy() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16_lossy(&utf16(s)),
-        |s| TestString::from_utf16_lossy_truncate(&utf16(s)),
-    );
-}
+            #[test]
+            fn try_from_iter() {
+                assert(
+                    |s| String::from_iter(vec![s]),
+                    |s| TestString::try_from_iterator(vec![s]),
+                );
+            }
 
-fn invalidate_utf16(buf: &mut [u16]) -> &mut [u16] {
-    if buf.len() >= 7 {
-        buf[0] = 0xD834;
-        buf[1] = 0xDD1E;
-        buf[2] = 0x006D;
-        buf[3] = 0x0075;
-        buf[4] = 0xD800;
-        buf[5] = 0x0069;
-        buf[6] = 0x0063;
-    }
-    buf
-}
+            #[test]
+            fn from_iter() {
+                assert(
+                    |s| String::from_iter(vec![s]),
+                    |s| TestString::from_iterator_truncate(vec![s]),
+                );
+            }
 
-#[test]
-fn try_from_utf16_invalid() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16(invalidate_utf16(&mut utf16(s))),
-        |s| TestString::try_from_utf16(invalidate_utf16(&mut utf16(s))),
-    );
-}
+            #[test]
+            fn try_from_utf16() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16(&utf16(s)),
+                    |s| TestString::try_from_utf16(&utf16(s)),
+                );
+            }
 
-#[test]
-fn from_utf16_invalid() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16(invalidate_utf16(&mut utf16(s))),
-        |s| TestString::from_utf16_truncate(invalidate_utf16(&mut utf16(s))),
-    );
-}
+            #[test]
+            fn from_utf16() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16(&utf16(s)),
+                    |s| TestString::from_utf16_truncate(&utf16(s)),
+                );
+            }
 
-#[test]
-fn from_utf16_lossy_invalid() {
-    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
-    assert(
-        |s| String::from_utf16_lossy(invalidate_utf16(&mut utf16(s))),
-        |s| TestString::from_utf16_lossy_truncate(invalidate_utf16(&mut utf16(s))),
-    );
-}
+            #[test]
+            fn from_utf16_lossy() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16_lossy(&utf16(s)),
+                    |s| TestString::from_utf16_lossy_truncate(&utf16(s)),
+                );
+            }
 
-#[test]
-fn try_push_str() {
-    assert(
-        |s| {
-            let mut st = String::from(s);
-            st.push_str(s);
-            st
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_push_str(s).map(|()| ms)
-        },
-    );
-}
+            #[test]
+            fn try_from_utf8() {
+                assert(
+                    |s| String::from_utf8(s.as_bytes().to_vec()),
+                    |s| TestString::try_from_utf8(s.as_bytes()),
+                );
+            }
 
-#[test]
-fn push_str() {
-    assert(
-        |s| {
-            let mut st = String::from(s);
-            st.push_str(s);
-            st
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.push_str_truncate(s);
-            ms
-        },
-    );
-}
+            #[test]
+            fn from_utf8() {
+                assert(
+                    |s| String::from_utf8(s.as_bytes().to_vec()),
+                    |s| TestString::from_utf8_truncate(s.as_bytes()),
+                );
+            }
 
-#[test]
-fn add_str() {
-    assert(
-        |s| String::from(s) + s,
-        |s| TestString::try_from_str(s).unwrap() + s,
-    );
-}
+            #[test]
+            fn from_utf8_lossy() {
+                assert(
+                    |s| String::from_utf8_lossy(s.as_bytes()).into_owned(),
+                    |s| TestString::from_utf8_lossy_truncate(s.as_bytes()),
+                );
+            }
 
-#[test]
-fn push() {
-    assert(
-        |s| {
-            let mut s = String::from(s);
-            s.push('🤔');
-            s
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_push('🤔').map(|()| ms)
-        },
-    );
+            #[test]
+            fn from_utf8_unchecked() {
+                assert(
+                    |s| String::from_utf8(s.as_bytes().to_vec()),
+                    |s| unsafe { TestString::from_utf8_unchecked(s.as_bytes()) },
+                );
+            }
+
+            fn invalidate_utf8(mut buf: Vec<u8>) -> Vec<u8> {
+                if buf.len() >= 4 {
+                    buf[1] = 0xF0;
+                    buf[2] = 0x90;
+                    buf[3] = 0x80;
+                }
+                buf
+            }
+
+            #[test]
+            fn try_from_utf8_invalid() {
+                assert(
+                    |s| String::from_utf8(invalidate_utf8(s.as_bytes().to_vec())),
+                    |s| TestString::try_from_utf8(invalidate_utf8(s.as_bytes().to_vec())),
+                );
+            }
+
+            #[test]
+            fn from_utf8_invalid() {
+                assert(
+                    |s| String::from_utf8(invalidate_utf8(s.as_bytes().to_vec())),
+                    |s| TestString::from_utf8_truncate(invalidate_utf8(s.as_bytes().to_vec())),
+                );
+            }
+
+            #[test]
+            fn from_utf8_lossy_invalid() {
+                assert(
+                    |s| String::from_utf8_lossy(&invalidate_utf8(s.as_bytes().to_vec())).into_owned(),
+                    |s| TestString::from_utf8_lossy_truncate(invalidate_utf8(s.as_bytes().to_vec())),
+                );
+            }
+
+            fn invalidate_utf16(buf: &mut [u16]) -> &mut [u16] {
+                if buf.len() >= 7 {
+                    buf[0] = 0xD834;
+                    buf[1] = 0xDD1E;
+                    buf[2] = 0x006D;
+                    buf[3] = 0x0075;
+                    buf[4] = 0xD800;
+                    buf[5] = 0x0069;
+                    buf[6] = 0x0063;
+                }
+                buf
+            }
+
+            #[test]
+            fn try_from_utf16_invalid() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16(invalidate_utf16(&mut utf16(s))),
+                    |s| TestString::try_from_utf16(invalidate_utf16(&mut utf16(s))),
+                );
+            }
+
+            #[test]
+            fn from_utf16_invalid() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16(invalidate_utf16(&mut utf16(s))),
+                    |s| TestString::from_utf16_truncate(invalidate_utf16(&mut utf16(s))),
+                );
+            }
+
+            #[test]
+            fn from_utf16_lossy_invalid() {
+                let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
+                assert(
+                    |s| String::from_utf16_lossy(invalidate_utf16(&mut utf16(s))),
+                    |s| TestString::from_utf16_lossy_truncate(invalidate_utf16(&mut utf16(s))),
+                );
+            }
+
+            #[test]
+            fn io_write() {
+                use std::io::Write;
+                assert(
+                    |s| String::from_utf8(s.as_bytes().to_vec()),
+                    |s| {
+                        let mut ms = TestString::new();
+                        ms.write_all(s.as_bytes()).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn io_write_split() {
+                use std::io::Write;
+                assert(
+                    |s| String::from_utf8(s.as_bytes().to_vec()),
+                    |s| {
+                        let mut ms = TestString::new();
+                        let mut writer = ms.writer();
+                        let mut bytes = s.as_bytes().chunks(1);
+                        bytes.try_for_each(|b| writer.write_all(b))?;
+                        writer.flush().map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn io_copy_reader() {
+                assert(
+                    |s| String::from(s),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        let mut copy = TestString::new();
+                        std::io::copy(&mut ms.reader(), &mut copy).map(|_| copy)
+                    },
+                );
+            }
+
+            #[test]
+            fn read_line_from() {
+                use std::io::BufRead;
+                assert(
+                    |s| {
+                        let mut input = std::io::Cursor::new(format!("{s}\n{s}"));
+                        let mut st = String::new();
+                        input.read_line(&mut st).map(|read| (st, read.to_string()))
+                    },
+                    |s| {
+                        let mut input = std::io::Cursor::new(format!("{s}\n{s}"));
+                        let mut ms = TestString::new();
+                        ms.read_line_from(&mut input).map(|read| match read {
+                            ReadLine::Complete(read) => (ms, read.to_string()),
+                            _ => unreachable!(),
+                        })
+                    },
+                );
+            }
+
+            #[test]
+            fn read_line_from_bytes() {
+                assert(
+                    |s| {
+                        let mut st = String::new();
+                        let mut input = std::io::Cursor::new(format!("{s}\n{s}"));
+                        std::io::BufRead::read_line(&mut input, &mut st).map(|_| st)
+                    },
+                    |s| {
+                        let input = format!("{s}\n{s}");
+                        let mut ms = TestString::new();
+                        ms.read_line_from_bytes_truncate(&mut input.bytes())
+                            .map(|_| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn replace_in_place() {
+                assert(
+                    |s| s.replace(' ', "🤔").replace("🤔🤔", "a").replace("", "-"),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.replace_in_place(' ', "🤔")?;
+                        ms.replace_in_place("🤔🤔", "a")?;
+                        ms.replace_in_place("", "-").map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn replacen_in_place() {
+                assert(
+                    |s| s.replacen(char::is_uppercase, "", 3).replacen("", "-", 2),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.replacen_in_place(char::is_uppercase, "", 3)?;
+                        ms.replacen_in_place("", "-", 2).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn replace_in_place_mixed() {
+                assert(
+                    |s| s.replace(|c: char| !c.is_ascii(), "xyz").replacen('a', "", 2),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.replace_in_place(|c: char| !c.is_ascii(), "xyz")?;
+                        ms.replacen_in_place('a', "", 2).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn replaced() {
+                assert(
+                    |s| s.replace(['a', 'h'], "🤔").replace("", "-"),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        let replaced = ms.replaced::<255>(['a', 'h'], "🤔")?;
+                        replaced.replaced::<255>("", "-").map(|r| r.to_string())
+                    },
+                );
+            }
+
+            #[test]
+            fn split_into() {
+                assert(
+                    |s| s.split(' ').chain(s.split("")).collect::<Vec<_>>().join("|"),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        let spaces = ms.split_into::<64, 64>(' ')?;
+                        let chars = ms.split_into::<64, 64>("")?;
+                        let parts = spaces.iter().chain(chars.iter());
+                        Ok::<_, OutOfBounds>(parts.map(|p| p.as_str()).collect::<Vec<_>>().join("|"))
+                    },
+                );
+            }
+
+            #[test]
+            fn split_once_owned() {
+                assert(
+                    |s| s.split_once(char::is_whitespace).map(|(a, b)| format!("{a}|{b}")),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.split_once_owned(char::is_whitespace).map(|(a, b)| format!("{a}|{b}"))
+                    },
+                );
+            }
+
+            #[test]
+            fn rsplit_once_owned() {
+                assert(
+                    |s| s.rsplit_once("🤔").map(|(a, b)| format!("{a}|{b}")),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.rsplit_once_owned("🤔").map(|(a, b)| format!("{a}|{b}"))
+                    },
+                );
+            }
+
+            #[test]
+            fn try_to_uppercase() {
+                assert(
+                    |s| s.to_uppercase(),
+                    |s| TestString::try_from_str(s).unwrap().try_to_uppercase(),
+                );
+            }
+
+            #[test]
+            fn try_to_lowercase() {
+                assert(
+                    |s| s.to_lowercase(),
+                    |s| TestString::try_from_str(s).unwrap().try_to_lowercase(),
+                );
+            }
+
+            #[test]
+            fn make_ascii_uppercase() {
+                assert(
+                    |s| s.to_ascii_uppercase(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.make_ascii_uppercase();
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn to_ascii_lowercase() {
+                assert(
+                    |s| s.to_ascii_lowercase(),
+                    |s| TestString::try_from_str(s).unwrap().to_ascii_lowercase(),
+                );
+            }
+
+            #[test]
+            fn resize() {
+                assert(
+                    |s| if s.len() <= 20 { Ok(s.to_owned()) } else { Err(()) },
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ArrayString::<20>::try_from(&ms).map(|s| s.to_string())
+                    },
+                );
+                assert(
+                    |s| if s.len() <= 63 { Ok(s.to_owned()) } else { Err(()) },
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        CacheString::try_from(&ms).map(|s| s.to_string())
+                    },
+                );
+                assert(
+                    |s| s.char_indices().take_while(|(i, c)| i + c.len_utf8() <= 20).map(|(_, c)| c).collect::<String>(),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.narrow_truncate::<20, u8>().to_string()
+                    },
+                );
+                assert(
+                    |s| s.to_owned(),
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.widen::<70_000, usize>().to_string()
+                    },
+                );
+            }
+
+            #[test]
+            fn comparisons() {
+                use std::borrow::Cow;
+                use std::cmp::Ordering;
+
+                for (a, b) in STRINGS.iter().zip(STRINGS.iter().cycle().skip(1)) {
+                    let ms = TestString::try_from_str(a).unwrap();
+                    let other = MaxString::from_str_truncate(b);
+                    let cache = CacheString::from_str_truncate(b);
+                    let expected = (*a).partial_cmp(other.as_str());
+                    assert_eq!(ms == other, a == &other.as_str());
+                    assert_eq!(ms.partial_cmp(&other), expected);
+                    assert_eq!(other.partial_cmp(&ms), expected.map(Ordering::reverse));
+                    assert_eq!(ms == cache, *a == cache.as_str());
+                    assert_eq!(cache == ms, *a == cache.as_str());
+                    assert_eq!(cache.partial_cmp(&ms), (*a).partial_cmp(cache.as_str()).map(Ordering::reverse));
+
+                    assert!(ms == *a && *a == ms && ms == **a && **a == ms);
+                    let owned = a.to_string();
+                    assert!(ms == owned && owned == ms);
+                    assert!(ms == Cow::Borrowed(*a) && Cow::<str>::Owned(a.to_string()) == ms);
+                    assert_eq!(ms.partial_cmp(*b), (*a).partial_cmp(*b));
+                    assert_eq!(b.to_string().partial_cmp(&ms), (*b).partial_cmp(*a));
+                    assert_eq!(Cow::Borrowed(*b).partial_cmp(&ms), (*b).partial_cmp(*a));
+                }
+            }
+
+            #[test]
+            fn owned_conversions() {
+                use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+                assert(
+                    |s| s.to_owned(),
+                    |s| TestString::try_from(s.to_owned()).map(String::from).map_err(|e| e.into_inner()).unwrap(),
+                );
+                assert(
+                    |s| s.to_owned(),
+                    |s| {
+                        let ms = TestString::try_from(Box::<str>::from(s)).unwrap();
+                        (&*Box::<str>::from(ms)).to_owned()
+                    },
+                );
+                assert(
+                    |s| (s.to_owned(), s.to_owned()),
+                    |s| {
+                        let ms = TestString::try_from(Cow::Borrowed(s)).unwrap();
+                        (Rc::<str>::from(ms).to_string(), Arc::<str>::from(ms).to_string())
+                    },
+                );
+                assert(
+                    |s| if s.len() <= 20 { Ok(s.to_owned()) } else { Err(s.to_owned()) },
+                    |s| ArrayString::<20>::try_from(s.to_owned()).map(String::from).map_err(|e| e.into_inner()),
+                );
+                assert(
+                    |s| if s.len() <= 63 { Ok(s.to_owned()) } else { Err(s.to_owned()) },
+                    |s| CacheString::try_from(Cow::<str>::Owned(s.to_owned())).map(String::from).map_err(|e| e.into_inner().into_owned()),
+                );
+            }
+
+            #[test]
+            fn error_context() {
+                for s in STRINGS {
+                    if let Err(err) = ArrayString::<20>::try_from_str(s) {
+                        assert_eq!((err.required(), err.capacity()), (s.len(), 20));
+                    }
+                    if let Err(err) = ArrayString::<20>::try_from_fmt(format_args!("{s}")) {
+                        assert_eq!((err.required(), err.capacity()), (s.len(), 20));
+                    }
+
+                    let mut bytes = s.as_bytes().to_vec();
+                    bytes.insert(bytes.len() / 2, 0xFF);
+                    let expected = std::str::from_utf8(&bytes).unwrap_err();
+                    match TestString::try_from_utf8(&bytes) {
+                        Err(arraystring::Error::Utf8(err)) => {
+                            assert_eq!(err.valid_up_to(), expected.valid_up_to());
+                            assert_eq!(err.error_len(), expected.error_len());
+                        }
+                        other => panic!("{other:?}"),
+                    }
+                }
+            }
+
+            #[test]
+            fn partial() {
+                fn split_fitting(s: &str, capacity: usize) -> (&str, &str) {
+                    let mut idx = capacity.min(s.len());
+                    while !s.is_char_boundary(idx) {
+                        idx -= 1;
+                    }
+                    s.split_at(idx)
+                }
+
+                assert(
+                    |s| {
+                        let (fits, rest) = split_fitting(s, 20 - 2);
+                        (format!("AB{fits}"), rest.to_owned())
+                    },
+                    |s| {
+                        let mut ms = ArrayString::<20>::try_from_str("AB").unwrap();
+                        let rest = ms.try_push_str_partial(s).err().unwrap_or_default();
+                        (ms.to_string(), rest.to_owned())
+                    },
+                );
+                assert(
+                    |s| {
+                        let (fits, rest) = split_fitting(s, 20 - 2);
+                        (format!("A{fits}B"), rest.to_owned())
+                    },
+                    |s| {
+                        let mut ms = ArrayString::<20>::try_from_str("AB").unwrap();
+                        let rest = ms.try_insert_str_partial(1, s).unwrap();
+                        (ms.to_string(), rest.to_owned())
+                    },
+                );
+                assert(
+                    |s| {
+                        let (fits, rest) = split_fitting(s, 20);
+                        (fits.to_owned(), rest.to_owned())
+                    },
+                    |s| match ArrayString::<20>::try_from_chars_partial(s.chars()) {
+                        Ok(ms) => (ms.to_string(), String::new()),
+                        Err((ms, rest)) => (ms.to_string(), rest.collect()),
+                    },
+                );
+                assert(
+                    |s| s.to_owned(),
+                    |s| {
+                        // Wraps words in lines, words that don't fit in a line are kept alone
+                        let mut lines = Vec::new();
+                        let mut words: Box<dyn Iterator<Item = &str>> = Box::new(s.split_inclusive(' '));
+                        loop {
+                            match ArrayString::<20>::try_from_iterator_partial(words) {
+                                Ok(line) => {
+                                    lines.push(line.to_string());
+                                    break;
+                                }
+                                Err((line, mut leftover)) => {
+                                    if line.is_empty() {
+                                        lines.extend(leftover.next().map(str::to_owned));
+                                    } else {
+                                        lines.push(line.to_string());
+                                    }
+                                    words = Box::new(leftover);
+                                }
+                            }
+                        }
+                        lines.concat()
+                    },
+                );
+            }
+
+            #[test]
+            fn error_kinds() {
+                use arraystring::Error;
+
+                for s in STRINGS {
+                    let ms = TestString::try_from_str(s).unwrap();
+                    for idx in 0..s.len() + 2 {
+                        let result = ms.clone().split_off(idx);
+                        if idx > s.len() {
+                            assert!(matches!(result, Err(Error::OutOfBounds(_))));
+                        } else if !s.is_char_boundary(idx) {
+                            assert!(matches!(result, Err(Error::NotCharBoundary(err)) if err.index() == idx));
+                        } else {
+                            assert!(result.is_ok());
+                        }
+                        if idx > 0 && idx <= s.len() {
+                            let result = ms.clone().replace_range(idx..idx - 1, "");
+                            assert!(matches!(result, Err(Error::InvalidRange(err)) if (err.start(), err.end()) == (idx, idx - 1)));
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn truncate_chars() {
+                for n in 0..10 {
+                    assert(
+                        |s| s.chars().take(n).collect::<String>(),
+                        |s| {
+                            let mut ms = TestString::try_from_str(s).unwrap();
+                            ms.truncate_chars(n);
+                            ms
+                        },
+                    );
+                }
+            }
+
+            #[test]
+            fn truncate_with_suffix() {
+                assert(
+                    |s| {
+                        let mut kept = 20 - "…".len();
+                        while !s.is_char_boundary(kept.min(s.len())) {
+                            kept -= 1;
+                        }
+                        format!("{}…", &s[..kept.min(s.len())])
+                    },
+                    |s| {
+                        let mut ms = ArrayString::<20>::from_str_truncate(s);
+                        ms.truncate_with_suffix("…").unwrap();
+                        ms.to_string()
+                    },
+                );
+            }
+
+            #[test]
+            fn try_from_fmt() {
+                assert(
+                    |s| format!("{s}{}{s:?}", s.len()),
+                    |s| TestString::try_from_fmt(format_args!("{s}{}{s:?}", s.len())),
+                );
+            }
+
+            #[test]
+            fn from_fmt_truncate() {
+                assert(
+                    |s| format!("{s}{}{s:?}", s.len()),
+                    |s| TestString::from_fmt_truncate(format_args!("{s}{}{s:?}", s.len())),
+                );
+            }
+
+            #[test]
+            fn try_push_str() {
+                assert(
+                    |s| {
+                        let mut st = String::from(s);
+                        st.push_str(s);
+                        st
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.try_push_str(s).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn push_str() {
+                assert(
+                    |s| {
+                        let mut st = String::from(s);
+                        st.push_str(s);
+                        st
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.push_str_truncate(s);
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn push_str_unchecked() {
+                assert(
+                    |s| {
+                        let mut st = String::from(s);
+                        st.push_str(s);
+                        st
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        unsafe { ms.push_str_unchecked(s) };
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn add_str() {
+                assert(
+                    |s| String::from(s) + s,
+                    |s| TestString::try_from_str(s).unwrap() + s,
+                );
+            }
+
+            #[test]
+            fn push() {
+                assert(
+                    |s| {
+                        let mut s = String::from(s);
+                        s.push('🤔');
+                        s
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.try_push('🤔').map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn truncate() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut s = String::from(s);
+                            s.truncate(2);
+                            s
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.truncate(2).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn pop() {
+                assert(
+                    |s| {
+                        let mut s = String::from(s);
+                        let old = s.pop();
+                        (s, old)
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        let old = ms.pop();
+                        (ms, old)
+                    },
+                );
+            }
+
+            #[test]
+            fn trim() {
+                assert(
+                    |s| String::from(s).trim().to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim();
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn trim_start_end() {
+                assert(
+                    |s| String::from(s).trim_start().to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_start();
+                        ms
+                    },
+                );
+                assert(
+                    |s| String::from(s).trim_end().to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_end();
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn trim_matches() {
+                assert(
+                    |s| s.trim_matches(['a', ' ', '🤔']).to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_matches(['a', ' ', '🤔']);
+                        ms
+                    },
+                );
+                assert(
+                    |s| s.trim_start_matches("a").to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_start_matches("a");
+                        ms
+                    },
+                );
+                assert(
+                    |s| s.trim_end_matches(char::is_alphanumeric).to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_end_matches(char::is_alphanumeric);
+                        ms
+                    },
+                );
+                assert(
+                    |s| s.trim_start_matches("").to_owned(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.trim_start_matches("");
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn strip_in_place() {
+                assert(
+                    |s| s.strip_prefix(' ').map(str::to_owned),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.strip_prefix_in_place(' ').then_some(ms)
+                    },
+                );
+                assert(
+                    |s| s.strip_suffix("  ").map(str::to_owned),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.strip_suffix_in_place("  ").then_some(ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn remove() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut s = String::from(s);
+                            let removed = s.remove(2);
+                            (removed, s)
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.remove(2).map(|r| (r, ms))
+                    },
+                );
+            }
+
+            #[test]
+            fn retain() {
+                assert(
+                    |s| {
+                        let mut s = String::from(s);
+                        s.retain(|c| c == 'a');
+                        s
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.retain(|c| c == 'a');
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn retain_indices() {
+                assert(
+                    |s| {
+                        let kept = s.char_indices().filter(|(i, c)| i % 3 != 0 && *c != ' ');
+                        kept.map(|(_, c)| c).collect::<String>()
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.retain_indices(|i, c| i % 3 != 0 && c != ' ');
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn retain_mut() {
+                assert(
+                    |s| {
+                        let mut s = s.to_ascii_lowercase();
+                        s.retain(|c| c != 'a');
+                        s
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.retain_mut(|c| {
+                            c.make_ascii_lowercase();
+                            *c != 'a'
+                        })
+                        .map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn retain_mut_resized() {
+                let change = |c: char| match c {
+                    ' ' => None,
+                    '🤔' => Some('x'),
+                    c if c.is_ascii_alphabetic() => Some('é'),
+                    c => Some(c),
+                };
+                assert(
+                    |s| s.chars().filter_map(change).collect::<String>(),
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.retain_mut(|c| match change(*c) {
+                            Some(changed) => {
+                                *c = changed;
+                                true
+                            }
+                            None => false,
+                        })
+                        .map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn dedup_by() {
+                assert(
+                    |s| {
+                        let mut chars = s.chars().collect::<Vec<_>>();
+                        chars.dedup_by(|a, b| a == b);
+                        chars.into_iter().collect::<String>()
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.dedup_by(|a, b| a == b);
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn try_insert() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut s = String::from(s);
+                            s.insert(2, 'a');
+                            s
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.try_insert(2, 'a').map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn try_insert_str() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut st = String::from(s);
+                            st.insert_str(2, s);
+                            st
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.try_insert_str(2, s).map(|()| ms)
+                    },
+                );
+            }
+
+            #[test]
+            fn insert_str_unchecked() {
+                assert(
+                    |s| {
+                        s.is_char_boundary(2).then(|| {
+                            let mut st = String::from(s);
+                            st.insert_str(2, s);
+                            st
+                        })
+                    },
+                    |s| {
+                        s.is_char_boundary(2).then(|| {
+                            let mut ms = TestString::try_from_str(s).unwrap();
+                            unsafe { ms.insert_str_unchecked(2, s) };
+                            ms
+                        })
+                    },
+                );
+            }
+
+            #[test]
+            fn insert_str() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut st = String::from(s);
+                            st.insert_str(2, s);
+                            (st, ())
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        let res = ms.insert_str_truncate(2, s);
+                        res.map(|()| (ms, ()))
+                    },
+                );
+            }
+
+            #[test]
+            fn clear() {
+                assert(
+                    |s| {
+                        let mut st = String::from(s);
+                        st.clear();
+                        st
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.clear();
+                        ms
+                    },
+                );
+            }
+
+            #[test]
+            fn split_off() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut st = String::from(s);
+                            let split = st.split_off(2);
+                            (st, split)
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.split_off(2).map(|s| (ms, s))
+                    },
+                );
+            }
+
+            #[test]
+            fn drain() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut st = String::from(s);
+                            let drained: String = st.drain(..2).collect();
+                            (st, drained)
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        let drained = ms.drain(..2).map(|d| d.collect::<String>());
+                        drained.map(|d| (ms, d))
+                    },
+                );
+            }
+
+            #[test]
+            fn replace_range() {
+                assert(
+                    |s| {
+                        unwind(move || {
+                            let mut st = String::from(s);
+                            st.replace_range(..2, s);
+                            (st, ())
+                        })
+                    },
+                    |s| {
+                        let mut ms = TestString::try_from_str(s).unwrap();
+                        ms.replace_range(..2, s).map(|()| (ms, ()))
+                    },
+                );
+            }
+
+            #[test]
+            fn len() {
+                assert(
+                    |s| {
+                        let st = String::from(s);
+                        st.len().to_string()
+                    },
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.len().to_string()
+                    },
+                );
+            }
+
+            #[test]
+            fn is_empty() {
+                assert(
+                    |s| {
+                        let st = String::from(s);
+                        st.is_empty().to_string()
+                    },
+                    |s| {
+                        let ms = TestString::try_from_str(s).unwrap();
+                        ms.is_empty().to_string()
+                    },
+                );
+            }
+
+            #[test]
+            fn new() {
+                assert_eq!(String::new().as_str(), TestString::new().as_str());
+            }
+        }
+        )*
+    };
 }
 
+parity_tests!(
+    u8_length: ArrayString<255>,
+    u16_length: ArrayString<1024, u16>,
+    usize_length: ArrayString<70_000, usize>,
+);
+
 #[test]
-fn truncate() {
+fn validated() {
+    use arraystring::validator::{Ascii, NonEmpty};
+
+    type Validated = ValidatedArrayString<255, (NonEmpty, Ascii)>;
+
     assert(
-        |s| {
-            unwind(move || {
-                let mut s = String::from(s);
-                s.truncate(2);
-                s
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.truncate(2).map(|()| ms)
-        },
+        |s| (!s.is_empty() && s.is_ascii()).then(|| s.to_owned()),
+        |s| Validated::try_from_str(s).ok().map(|ms| ms.to_string()),
     );
-}
-
-#[test]
-fn pop() {
     assert(
+        |s| s.chars().next(),
         |s| {
-            let mut s = String::from(s);
-            let old = s.pop();
-            (s, old)
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            let old = ms.pop();
-            (ms, old)
+            let mut ms = ValidatedArrayString::<255, NonEmpty>::try_from_str(s).ok()?;
+            while let Ok(Some(_)) = ms.pop() {}
+            ms.chars().next()
         },
     );
-}
-
-#[test]
-fn trim() {
     assert(
-        |s| String::from(s).trim().to_owned(),
+        |s| (!s.is_empty() && s.is_ascii()).then(|| s.to_owned()),
         |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.trim();
-            ms
+            let mut ms = Validated::try_from_str(s).ok()?;
+            ms.retain(|_| false).err()?;
+            ms.clear().err()?;
+            ms.try_push('é').err()?;
+            ms.drain(..).err()?;
+            Some(ms.to_string())
         },
     );
 }
 
 #[test]
-fn remove() {
+fn bounded() {
     assert(
+        |s| (2..=20).contains(&s.len()).then(|| s.to_owned()),
         |s| {
-            unwind(move || {
-                let mut s = String::from(s);
-                let removed = s.remove(2);
-                (removed, s)
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.remove(2).map(|r| (r, ms))
+            BoundedString::<2, 20>::try_from_str(s)
+                .ok()
+                .map(|ms| ms.to_string())
         },
     );
-}
-
-#[test]
-fn retain() {
     assert(
         |s| {
-            let mut s = String::from(s);
-            s.retain(|c| c == 'a');
-            s
+            let mut popped = s.to_owned();
+            let _ = popped.pop();
+            match (s.len(), popped.len()) {
+                (..2, _) => None,
+                (_, 2..) => Some(popped),
+                _ => Some(s.to_owned()),
+            }
         },
         |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.retain(|c| c == 'a');
-            ms
+            let mut ms = BoundedString::<2, 255>::try_from_str(s).ok()?;
+            let _ = ms.pop();
+            Some(ms.to_string())
         },
     );
-}
-
-#[test]
-fn try_insert() {
     assert(
+        |s| (s.len() == 3).then(|| s.to_owned()),
         |s| {
-            unwind(move || {
-                let mut s = String::from(s);
-                s.insert(2, 'a');
-                s
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_insert(2, 'a').map(|()| ms)
+            let mut ms = FixedString::<3>::try_from_str(s).ok()?;
+            ms.clear().err()?;
+            ms.drain(1..).err()?;
+            ms.truncate(0).err()?;
+            Some(ms.to_string())
         },
     );
 }
 
 #[test]
-fn try_insert_str() {
+fn ascii() {
+    type Ascii = AsciiArrayString<255>;
+
     assert(
-        |s| {
-            unwind(move || {
-                let mut st = String::from(s);
-                st.insert_str(2, s);
-                st
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_insert_str(2, s).map(|()| ms)
-        },
+        |s| s.is_ascii().then(|| s.to_owned()),
+        |s| Ascii::try_from_str(s).ok().map(|ms| ms.to_string()),
     );
-}
-
-#[test]
-fn insert_str() {
     assert(
         |s| {
-            unwind(move || {
-                let mut st = String::from(s);
-                st.insert_str(2, s);
-                (st, ())
-            })
+            let mut s = s.to_owned();
+            s.is_ascii().then(|| format!("{:?}", (s.pop(), s)))
         },
         |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            let res = ms.insert_str_truncate(2, s);
-            res.map(|()| (ms, ()))
+            let mut ms = Ascii::try_from_str(s).ok()?;
+            Some(format!("{:?}", (ms.pop(), ms.to_string())))
         },
     );
-}
-
-#[test]
-fn clear() {
     assert(
         |s| {
-            let mut st = String::from(s);
-            st.clear();
-            st
+            let mut s = s.to_owned();
+            let removed = (s.is_ascii() && s.len() > 1).then(|| s.remove(1));
+            s.insert(0, 'A');
+            s.is_ascii().then(|| format!("{:?}", (removed, s)))
         },
         |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.clear();
-            ms
+            let mut ms = Ascii::try_from_str(s).ok()?;
+            let removed = ms.remove(1).ok();
+            ms.try_insert(0, 'A').ok()?;
+            Some(format!("{:?}", (removed, ms.to_string())))
         },
     );
-}
-
-#[test]
-fn split_off() {
     assert(
+        |s| s.is_ascii().then(|| s.chars().rev().collect::<String>()),
         |s| {
-            unwind(move || {
-                let mut st = String::from(s);
-                let split = st.split_off(2);
-                (st, split)
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.split_off(2).map(|s| (ms, s))
+            Some(
+                Ascii::try_from_str(s)
+                    .ok()?
+                    .chars()
+                    .rev()
+                    .collect::<String>(),
+            )
         },
     );
+    for s in STRINGS {
+        let result = Ascii::try_from(ArrayString::<255>::try_from_str(s).unwrap());
+        match s.bytes().position(|b| !b.is_ascii()) {
+            Some(idx) => assert_eq!(result.unwrap_err().index(), idx),
+            None => assert_eq!(ArrayString::from(result.unwrap()), s),
+        }
+    }
 }
 
 #[test]
-fn drain() {
-    assert(
-        |s| {
-            unwind(move || {
-                let mut st = String::from(s);
-                let drained: String = st.drain(..2).collect();
-                (st, drained)
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            let drained = ms.drain(..2).map(|d| d.collect::<String>());
-            drained.map(|d| (ms, d))
-        },
+fn io_write_split() {
+    use std::io::{ErrorKind, Write};
+    let invalid = |result: std::io::Result<usize>| result.unwrap_err().kind();
+
+    // A direct write has nowhere to keep a split char
+    let mut ms = ArrayString::<8>::new();
+    assert_eq!(invalid(ms.write(b"\xC3")), ErrorKind::InvalidData);
+    assert_eq!(invalid(ms.write(b"\xA9")), ErrorKind::InvalidData);
+    assert_eq!(ms, "");
+
+    // Interrupted by a byte that can't continue the char
+    let mut writer = ms.writer();
+    assert_eq!(writer.write(b"\xC3").unwrap(), 1);
+    assert_eq!(invalid(writer.write(b"a")), ErrorKind::InvalidData);
+    assert!(writer.pending().is_empty());
+    assert_eq!(writer.write(b"a").unwrap(), 1);
+    assert_eq!(ms, "a");
+
+    // Cleared and copied strings don't keep a split char
+    let mut writer = ms.writer();
+    assert_eq!(writer.write(b"\xE2\x82").unwrap(), 2);
+    assert_eq!(invalid(writer.flush().map(|()| 0)), ErrorKind::InvalidData);
+    ms.clear();
+    let mut copy = ms;
+    assert_eq!(
+        invalid(copy.writer().write(b"\xAC")),
+        ErrorKind::InvalidData
     );
-}
+    assert_eq!(invalid(copy.write(b"\xAC")), ErrorKind::InvalidData);
+    assert_eq!(copy, "");
 
-#[test]
-fn replace_range() {
-    assert(
-        |s| {
-            unwind(move || {
-                let mut st = String::from(s);
-                st.replace_range(..2, s);
-                (st, ())
-            })
-        },
-        |s| {
-            let mut ms = TestString::try_from_str(s).unwrap();
-            ms.replace_range(..2, s).map(|()| (ms, ()))
-        },
+    // Bytes left in the spare capacity are never read back
+    let mut ms = ArrayString::<8>::new();
+    for (spare, byte) in ms
+        .spare_capacity_mut()
+        .iter_mut()
+        .zip([0xE2, 0xF9, 0x82, 0xAC])
+    {
+        spare.write(byte);
+    }
+    assert_eq!(
+        invalid(ms.writer().write(b"\x82\xAC")),
+        ErrorKind::InvalidData
     );
-}
+    assert_eq!(invalid(ms.write(b"\x82\xAC")), ErrorKind::InvalidData);
+    assert_eq!(ms, "");
 
-#[test]
-fn len() {
-    assert(
-        |s| {
-            let st = String::from(s);
-            st.len().to_string()
-        },
-        |s| {
-            let ms = TestString::try_from_str(s).unwrap();
-            ms.len().to_string()
-        },
+    // Only kept if the whole char fits
+    let mut ms = ArrayString::<2>::try_from_str("a").unwrap();
+    assert_eq!(
+        invalid(ms.writer().write(b"\xE2\x82")),
+        ErrorKind::WriteZero
     );
+    assert_eq!(ms, "a");
 }
 
 #[test]
-fn is_empty() {
-    assert(
-        |s| {
-            let st = String::from(s);
-            st.is_empty().to_string()
-        },
-        |s| {
-            let ms = TestString::try_from_str(s).unwrap();
-            ms.is_empty().to_string()
-        },
-    );
+fn replace_in_place_capacity() {
+    let mut ms = ArrayString::<6>::try_from_str("é🤔").unwrap();
+    ms.replace_in_place(|c: char| !c.is_ascii(), "xyz").unwrap();
+    assert_eq!(ms, "xyzxyz");
+
+    let mut ms = ArrayString::<4>::try_from_str("aaaa").unwrap();
+    let err = ms.replace_in_place('a', "bb").unwrap_err();
+    assert_eq!((err.required(), err.capacity()), (8, 4));
+    assert_eq!(ms, "aaaa");
+
+    let mut ms = ArrayString::<6>::try_from_str("é🤔").unwrap();
+    let err = ms
+        .replacen_in_place(|c: char| !c.is_ascii(), "xyz", 1)
+        .unwrap_err();
+    assert_eq!((err.required(), err.capacity()), (7, 6));
+    assert_eq!(ms, "é🤔");
 }
 
 #[test]
-fn new() {
-    assert_eq!(String::new().as_str(), TestString::new().as_str());
+fn retain_mut_overflow() {
+    let mut ms = ArrayString::<6>::try_from_str("ab🤔").unwrap();
+    let err = ms.retain_mut(|c| {
+        *c = '🤔';
+        true
+    });
+    assert_eq!(err.unwrap_err().required(), 12);
+    assert_eq!(ms, "🤔");
+
+    let mut ms = ArrayString::<6>::try_from_str("ab🤔").unwrap();
+    let mut seen = 0;
+    let panicked = unwind(|| {
+        ms.retain_mut(|c| {
+            seen += 1;
+            assert!(seen < 3);
+            *c = 'é';
+            true
+        })
+    });
+    assert!(panicked.is_err());
+    assert_eq!(ms, "");
 }
 
 // Internal hackery to make the function `assert` possible
@@ -428,19 +1377,13 @@ impl Normalize<Result<String, ()>> for () {
     }
 }
 
-impl Normalize<Result<String, ()>> for TestString {
-    fn normalize(&self) -> Result<String, ()> {
-        Ok(self.as_str().to_owned())
-    }
-}
-
 impl Normalize<Result<String, ()>> for String {
     fn normalize(&self) -> Result<String, ()> {
         Ok(self.as_str().to_owned())
     }
 }
 
-impl<'a> Normalize<Result<String, ()>> for &'a str {
+impl Normalize<Result<String, ()>> for &str {
     fn normalize(&self) -> Result<String, ()> {
         Ok(self.to_string())
     }